@@ -0,0 +1,112 @@
+use sdl2::{
+  controller::{Axis, Button},
+  keyboard::Keycode,
+};
+use skia_test::input::{Binding, InputMap};
+use std::{
+  env, fs,
+  io::{self, ErrorKind},
+  path::{Path, PathBuf},
+};
+
+// Players rebind controls by editing this file in their config dir, which is created with the default bindings on
+// first run
+const CONTROLS_FILE_NAME: &str = "controls.json";
+const APP_DIR_NAME: &str = "snake";
+
+pub(super) const TURN_UP: &str = "turn_up";
+pub(super) const TURN_RIGHT: &str = "turn_right";
+pub(super) const TURN_DOWN: &str = "turn_down";
+pub(super) const TURN_LEFT: &str = "turn_left";
+
+pub(super) fn load() -> InputMap {
+  let mut input_map = InputMap::default()
+    .with_action(
+      TURN_UP,
+      [
+        Binding::Key(Keycode::W),
+        Binding::Key(Keycode::Up),
+        Binding::ControllerButton(Button::DPadUp),
+        Binding::ControllerAxis {
+          axis: Axis::LeftY,
+          is_positive: false,
+        },
+      ],
+    )
+    .with_action(
+      TURN_RIGHT,
+      [
+        Binding::Key(Keycode::D),
+        Binding::Key(Keycode::Right),
+        Binding::ControllerButton(Button::DPadRight),
+        Binding::ControllerAxis {
+          axis: Axis::LeftX,
+          is_positive: true,
+        },
+      ],
+    )
+    .with_action(
+      TURN_DOWN,
+      [
+        Binding::Key(Keycode::S),
+        Binding::Key(Keycode::Down),
+        Binding::ControllerButton(Button::DPadDown),
+        Binding::ControllerAxis {
+          axis: Axis::LeftY,
+          is_positive: true,
+        },
+      ],
+    )
+    .with_action(
+      TURN_LEFT,
+      [
+        Binding::Key(Keycode::A),
+        Binding::Key(Keycode::Left),
+        Binding::ControllerButton(Button::DPadLeft),
+        Binding::ControllerAxis {
+          axis: Axis::LeftX,
+          is_positive: false,
+        },
+      ],
+    );
+
+  let path = get_controls_path();
+
+  match input_map.load(&path) {
+    Ok(()) => {},
+    Err(err) if err.kind() == ErrorKind::NotFound => {
+      if let Err(err) = save(&input_map, &path) {
+        eprintln!("Failed to save {}: {err}", path.display());
+      }
+    },
+
+    // Keep playing with the default bindings rather than crashing on a broken config file
+    Err(err) => eprintln!("Failed to load {}: {err}", path.display()),
+  }
+
+  input_map
+}
+
+// %APPDATA%\snake\controls.json on Windows and $XDG_CONFIG_HOME/snake/controls.json elsewhere, which defaults to
+// ~/.config/snake/controls.json. Falls back to the current dir if none of them is set.
+fn get_controls_path() -> PathBuf {
+  let config_dir = if cfg!(windows) {
+    env::var_os("APPDATA").map(PathBuf::from)
+  } else {
+    env::var_os("XDG_CONFIG_HOME")
+      .map(PathBuf::from)
+      .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+  };
+
+  config_dir
+    .map_or_else(PathBuf::new, |config_dir| config_dir.join(APP_DIR_NAME))
+    .join(CONTROLS_FILE_NAME)
+}
+
+fn save(input_map: &InputMap, path: &Path) -> io::Result<()> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+
+  input_map.save(path)
+}
@@ -1,8 +1,12 @@
-use crate::models::{Snake, SnakePart};
-use sdl2::{event::Event, keyboard::Keycode};
+use crate::{
+  controls::{TURN_DOWN, TURN_LEFT, TURN_RIGHT, TURN_UP},
+  models::{Snake, SnakePart},
+};
+use sdl2::event::Event;
 use skia_safe::Color;
 use skia_test::{
   common::{Clock, Sharable, SparseSet},
+  input::Binding,
   layouts::{stateful_layout::State, StatefulLayout},
   models::{direction::DIRECTIONS, Box2D, Direction},
   nodes::{BoxNode, GridNode},
@@ -10,8 +14,7 @@ use skia_test::{
   Context, View,
 };
 use std::{cell::RefCell, collections::VecDeque, rc::Rc};
-use tinyrand::RandRange;
-use tinyrand_std::thread_rand;
+use tinyrand::{Rand, RandRange, StdRand};
 
 const DIM: u16 = 31; // Follow the dimension of the below data grid
 
@@ -32,18 +35,21 @@ const DIE_SOUND: &str = "die";
 const EAT_SOUND: &str = "eat";
 const TURN_SOUND: &str = "turn";
 
+const TURNS: &[(&str, Direction)] = &[
+  (TURN_UP, Direction::Up),
+  (TURN_RIGHT, Direction::Right),
+  (TURN_DOWN, Direction::Down),
+  (TURN_LEFT, Direction::Left),
+];
+
 #[derive(Default)]
 pub(crate) struct SnakeGrid {
   pub on_die: Option<Box<dyn FnMut()>>,
 }
 
 impl StatefulLayout for SnakeGrid {
-  fn get_key(&self) -> &str {
-    "layouts/snake_grid"
-  }
-
-  fn make_state(&mut self) -> Rc<RefCell<dyn State>> {
-    Rc::new(RefCell::new(SnakeGridState::new(self.on_die.take())))
+  fn make_state(&mut self, context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(SnakeGridState::new(self.on_die.take(), context.get_rng())))
   }
 }
 
@@ -59,14 +65,14 @@ struct SnakeGridState<OnDie> {
 
 impl<OnDie> Default for SnakeGridState<OnDie> {
   fn default() -> Self {
-    Self::new(None)
+    Self::new(None, &mut StdRand::default())
   }
 }
 
 impl<OnDie> SnakeGridState<OnDie> {
-  fn new(on_die: Option<OnDie>) -> Self {
+  fn new(on_die: Option<OnDie>, rng: &mut impl Rand) -> Self {
     // Initialize snake moving direction
-    let snake_direction = DIRECTIONS[thread_rand().next_range(0..DIRECTIONS.len())];
+    let snake_direction = DIRECTIONS[rng.next_range(0..DIRECTIONS.len())];
 
     #[rustfmt::skip]
     let data = Rc::new(RefCell::new(vec![ // Make sure DIM constant above follows the dimension of this data grid
@@ -131,87 +137,82 @@ impl<OnDie> SnakeGridState<OnDie> {
     // The center of the data grid is already allocated by the snake, so remove this data grid index from the free list.
     this.air_indices.remove((DIM >> 1) * DIM + (DIM >> 1));
 
-    this.spawn_food();
+    this.spawn_food(rng);
     this
   }
 
-  fn spawn_food(&mut self) {
+  // Turns the snake head unless it would move along the same axis, which is a no-op or a U-turn into itself
+  fn turn(&mut self, context: &mut Context, direction: Direction) -> bool {
+    let is_turnable = match direction {
+      Direction::Up | Direction::Down => matches!(self.snake.head.direction, Direction::Right | Direction::Left),
+      Direction::Right | Direction::Left => matches!(self.snake.head.direction, Direction::Up | Direction::Down),
+    };
+
+    if !is_turnable {
+      return false;
+    }
+
+    self.snake.head.direction = direction;
+
+    self.snake.joint_queue.push_back(SnakePart {
+      position: self.snake.head.position,
+      direction,
+    });
+
+    // Change the snake direction at most once per move, so that turning twice quickly never reverses into itself
+    self.change_snake_direction = false;
+
+    context.play_sound(TURN_SOUND);
+    true
+  }
+
+  fn spawn_food(&mut self, rng: &mut impl Rand) {
     // Spawn a food at a random free location
-    self.data.borrow_mut()[self.air_indices.remove_random_key() as usize] = FOOD;
+    self.data.borrow_mut()[self.air_indices.remove_random_key(rng) as usize] = FOOD;
   }
 }
 
 impl<OnDie: FnMut()> State for SnakeGridState<OnDie> {
+  // Take focus so that the snake is steered by default while menus can still take focus from it
+  fn on_mount(&mut self, context: &mut Context) {
+    context.request_focus();
+  }
+
   fn on_event(&mut self, context: &mut Context, event: &Event) {
-    if !self.clock.is_running() || !self.change_snake_direction {
+    // Keep the keys bound to the snake from moving focus away while this grid has focus
+    if !context.has_focus() {
       return;
     }
 
     if let Event::KeyDown {
       keycode: Some(keycode), ..
-    } = event
+    } = *event
     {
-      // Assume the snake has already changed its moving direction
-      self.change_snake_direction = false;
-
-      match keycode {
-        Keycode::W | Keycode::Up
-          if self.snake.head.direction != Direction::Up && self.snake.head.direction != Direction::Down =>
-        {
-          self.snake.head.direction = Direction::Up;
-
-          self.snake.joint_queue.push_back(SnakePart {
-            position: self.snake.head.position,
-            direction: Direction::Up,
-          });
+      let input_map = context.get_input_map();
 
-          context.play_sound(TURN_SOUND);
-        },
-        Keycode::D | Keycode::Right
-          if self.snake.head.direction != Direction::Right && self.snake.head.direction != Direction::Left =>
-        {
-          self.snake.head.direction = Direction::Right;
-
-          self.snake.joint_queue.push_back(SnakePart {
-            position: self.snake.head.position,
-            direction: Direction::Right,
-          });
-
-          context.play_sound(TURN_SOUND);
-        },
-        Keycode::S | Keycode::Down
-          if self.snake.head.direction != Direction::Down && self.snake.head.direction != Direction::Up =>
-        {
-          self.snake.head.direction = Direction::Down;
-
-          self.snake.joint_queue.push_back(SnakePart {
-            position: self.snake.head.position,
-            direction: Direction::Down,
-          });
-
-          context.play_sound(TURN_SOUND);
-        },
-        Keycode::A | Keycode::Left
-          if self.snake.head.direction != Direction::Left && self.snake.head.direction != Direction::Right =>
-        {
-          self.snake.head.direction = Direction::Left;
-
-          self.snake.joint_queue.push_back(SnakePart {
-            position: self.snake.head.position,
-            direction: Direction::Left,
-          });
-
-          context.play_sound(TURN_SOUND);
-        },
-        _ => {
-          // Assumption above failed. Rollback this variable
-          self.change_snake_direction = true;
-        },
+      if TURNS
+        .iter()
+        .any(|&(action, _)| input_map.is_bound(action, Binding::Key(keycode)))
+      {
+        context.stop_propagation();
       }
     }
   }
 
+  fn is_focusable(&self) -> bool {
+    true
+  }
+
   fn tick(&mut self, context: &mut Context, dt: f32) {
+    // Only control the snake while this grid has focus
+    if context.has_focus() && self.clock.is_running() && self.change_snake_direction {
+      for &(action, direction) in TURNS {
+        if context.is_action_just_pressed(action) && self.turn(context, direction) {
+          break;
+        }
+      }
+    }
+
     let mut is_food_eaten = false;
 
     self.clock.advance(dt, |clock| {
@@ -282,7 +283,7 @@ impl<OnDie: FnMut()> State for SnakeGridState<OnDie> {
     });
 
     if is_food_eaten {
-      self.spawn_food();
+      self.spawn_food(context.get_rng());
       context.play_sound(EAT_SOUND);
     }
   }
@@ -297,7 +298,7 @@ impl<OnDie: FnMut()> State for SnakeGridState<OnDie> {
 
         Box::new(move |index| {
           Box::new(BoxNode {
-            color: COLORS[data.borrow()[index] as usize],
+            style: COLORS[data.borrow()[index] as usize].into(),
           })
         })
       },
@@ -0,0 +1,12 @@
+// Something that changes over time. States usually advance their animations in tick(), then call
+// context.mark_dirty() to remake their children with the new values.
+pub trait Animation {
+  // Advances this animation by dt seconds and returns the part of dt left over after this animation has finished, so
+  // that the next animation in a sequence can start at the exact time
+  fn advance(&mut self, dt: f32) -> f32;
+
+  fn is_finished(&self) -> bool;
+
+  // Starts this animation over from the beginning
+  fn reset(&mut self);
+}
@@ -0,0 +1,208 @@
+use std::f32::consts::PI;
+
+// A cubic Bézier curve from (0, 0) to (1, 1) with the given control points, the same as cubic-bezier() in CSS
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CubicBezier {
+  pub x1: f32,
+  pub y1: f32,
+  pub x2: f32,
+  pub y2: f32,
+}
+
+impl CubicBezier {
+  pub const EASE: CubicBezier = CubicBezier::new(0.25f32, 0.1f32, 0.25f32, 1f32);
+  pub const EASE_IN: CubicBezier = CubicBezier::new(0.42f32, 0f32, 1f32, 1f32);
+  pub const EASE_OUT: CubicBezier = CubicBezier::new(0f32, 0f32, 0.58f32, 1f32);
+  pub const EASE_IN_OUT: CubicBezier = CubicBezier::new(0.42f32, 0f32, 0.58f32, 1f32);
+
+  pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+    Self { x1, y1, x2, y2 }
+  }
+
+  // Evaluates one coordinate of this curve at the given curve parameter s, given the control point coordinates
+  fn sample(p1: f32, p2: f32, s: f32) -> f32 {
+    let inv = 1f32 - s;
+    3f32 * inv * inv * s * p1 + 3f32 * inv * s * s * p2 + s * s * s
+  }
+
+  fn sample_slope(p1: f32, p2: f32, s: f32) -> f32 {
+    let inv = 1f32 - s;
+    3f32 * inv * inv * p1 + 6f32 * inv * s * (p2 - p1) + 3f32 * s * s * (1f32 - p2)
+  }
+
+  // Finds the curve parameter whose x is the given x, first by Newton's method which converges fast on most curves,
+  // then falls back to bisection which always converges because x increases monotonically with s
+  fn solve(self, x: f32) -> f32 {
+    let mut s = x;
+
+    for _ in 0..8 {
+      let error = CubicBezier::sample(self.x1, self.x2, s) - x;
+
+      if error.abs() < 1e-6f32 {
+        return s;
+      }
+
+      let slope = CubicBezier::sample_slope(self.x1, self.x2, s);
+
+      if slope.abs() < 1e-6f32 {
+        break;
+      }
+
+      s -= error / slope;
+    }
+
+    let (mut low, mut high) = (0f32, 1f32);
+    s = x;
+
+    while high - low > 1e-6f32 {
+      if CubicBezier::sample(self.x1, self.x2, s) < x {
+        low = s;
+      } else {
+        high = s;
+      }
+
+      s = (low + high) / 2f32;
+    }
+
+    s
+  }
+
+  pub fn ease(self, t: f32) -> f32 {
+    // Preconditions
+    debug_assert!(
+      (0f32..=1f32).contains(&self.x1) && (0f32..=1f32).contains(&self.x2),
+      "x1 and x2 must be between 0 and 1"
+    );
+
+    CubicBezier::sample(self.y1, self.y2, self.solve(t.clamp(0f32, 1f32)))
+  }
+}
+
+// Maps the linear progress of an animation between 0 and 1 to its eased progress. The eased progress starts at 0 and
+// ends at 1, but Back and Elastic curves overshoot in between.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Easing {
+  #[default]
+  Linear,
+  InQuad,
+  OutQuad,
+  InOutQuad,
+  InCubic,
+  OutCubic,
+  InOutCubic,
+  InSine,
+  OutSine,
+  InOutSine,
+  InExpo,
+  OutExpo,
+  InOutExpo,
+  InBack,
+  OutBack,
+  InOutBack,
+  InElastic,
+  OutElastic,
+  InBounce,
+  OutBounce,
+  CubicBezier(CubicBezier),
+}
+
+impl Easing {
+  pub fn ease(self, t: f32) -> f32 {
+    // How far Back curves overshoot
+    const C1: f32 = 1.70158f32;
+    const C2: f32 = C1 * 1.525f32;
+    const C3: f32 = C1 + 1f32;
+
+    let t = t.clamp(0f32, 1f32);
+
+    match self {
+      Self::Linear => t,
+      Self::InQuad => t * t,
+      Self::OutQuad => 1f32 - (1f32 - t) * (1f32 - t),
+      Self::InOutQuad => {
+        if t < 0.5f32 {
+          2f32 * t * t
+        } else {
+          1f32 - (-2f32 * t + 2f32).powi(2) / 2f32
+        }
+      },
+      Self::InCubic => t * t * t,
+      Self::OutCubic => 1f32 - (1f32 - t).powi(3),
+      Self::InOutCubic => {
+        if t < 0.5f32 {
+          4f32 * t * t * t
+        } else {
+          1f32 - (-2f32 * t + 2f32).powi(3) / 2f32
+        }
+      },
+      Self::InSine => 1f32 - (t * PI / 2f32).cos(),
+      Self::OutSine => (t * PI / 2f32).sin(),
+      Self::InOutSine => -((PI * t).cos() - 1f32) / 2f32,
+      Self::InExpo => {
+        if t == 0f32 {
+          0f32
+        } else {
+          2f32.powf(10f32 * t - 10f32)
+        }
+      },
+      Self::OutExpo => {
+        if t == 1f32 {
+          1f32
+        } else {
+          1f32 - 2f32.powf(-10f32 * t)
+        }
+      },
+      Self::InOutExpo => {
+        if t == 0f32 || t == 1f32 {
+          t
+        } else if t < 0.5f32 {
+          2f32.powf(20f32 * t - 10f32) / 2f32
+        } else {
+          (2f32 - 2f32.powf(-20f32 * t + 10f32)) / 2f32
+        }
+      },
+      Self::InBack => C3 * t * t * t - C1 * t * t,
+      Self::OutBack => 1f32 + C3 * (t - 1f32).powi(3) + C1 * (t - 1f32).powi(2),
+      Self::InOutBack => {
+        if t < 0.5f32 {
+          (2f32 * t).powi(2) * ((C2 + 1f32) * 2f32 * t - C2) / 2f32
+        } else {
+          ((2f32 * t - 2f32).powi(2) * ((C2 + 1f32) * (t * 2f32 - 2f32) + C2) + 2f32) / 2f32
+        }
+      },
+      Self::InElastic => 1f32 - Self::OutElastic.ease(1f32 - t),
+      Self::OutElastic => {
+        if t == 0f32 || t == 1f32 {
+          t
+        } else {
+          2f32.powf(-10f32 * t) * ((t * 10f32 - 0.75f32) * (2f32 * PI / 3f32)).sin() + 1f32
+        }
+      },
+      Self::InBounce => 1f32 - Self::OutBounce.ease(1f32 - t),
+      Self::OutBounce => {
+        const N1: f32 = 7.5625f32;
+        const D1: f32 = 2.75f32;
+
+        if t < 1f32 / D1 {
+          N1 * t * t
+        } else if t < 2f32 / D1 {
+          let t = t - 1.5f32 / D1;
+          N1 * t * t + 0.75f32
+        } else if t < 2.5f32 / D1 {
+          let t = t - 2.25f32 / D1;
+          N1 * t * t + 0.9375f32
+        } else {
+          let t = t - 2.625f32 / D1;
+          N1 * t * t + 0.984375f32
+        }
+      },
+      Self::CubicBezier(curve) => curve.ease(t),
+    }
+  }
+}
+
+impl From<CubicBezier> for Easing {
+  fn from(value: CubicBezier) -> Self {
+    Self::CubicBezier(value)
+  }
+}
@@ -0,0 +1,48 @@
+use crate::models::Box2D;
+use skia_safe::{Color, Point};
+
+// Linear interpolation, where t is usually between 0 and 1 but can go beyond them when an easing curve or spring
+// overshoots
+pub trait Lerp {
+  fn lerp(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    self + (to - self) * t
+  }
+}
+
+impl Lerp for (f32, f32) {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    (self.0.lerp(&to.0, t), self.1.lerp(&to.1, t))
+  }
+}
+
+impl Lerp for Point {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    Point::new(self.x.lerp(&to.x, t), self.y.lerp(&to.y, t))
+  }
+}
+
+impl Lerp for Box2D {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    Box2D {
+      position: self.position.lerp(&to.position, t),
+      size: self.size.lerp(&to.size, t),
+    }
+  }
+}
+
+impl Lerp for Color {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    let lerp_channel = |from: u8, to: u8| (from as f32).lerp(&(to as f32), t).round().clamp(0f32, 255f32) as u8;
+
+    Color::from_argb(
+      lerp_channel(self.a(), to.a()),
+      lerp_channel(self.r(), to.r()),
+      lerp_channel(self.g(), to.g()),
+      lerp_channel(self.b(), to.b()),
+    )
+  }
+}
@@ -0,0 +1,15 @@
+pub mod animation;
+pub mod easing;
+pub mod lerp;
+pub mod parallel;
+pub mod sequence;
+pub mod spring;
+pub mod tween;
+
+pub use animation::Animation;
+pub use easing::{CubicBezier, Easing};
+pub use lerp::Lerp;
+pub use parallel::Parallel;
+pub use sequence::Sequence;
+pub use spring::Spring;
+pub use tween::{Repeat, Tween};
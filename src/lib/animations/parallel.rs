@@ -0,0 +1,57 @@
+use super::Animation;
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Plays the given animations at the same time until all of them have finished. The caller keeps its own references to
+// the given animations to read their values.
+pub struct Parallel {
+  animations: Vec<Rc<RefCell<dyn Animation>>>,
+}
+
+impl Debug for Parallel {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Parallel")
+      .field("len", &self.animations.len())
+      .field("is_finished", &self.is_finished())
+      .finish_non_exhaustive()
+  }
+}
+
+impl Parallel {
+  pub fn new(animations: Vec<Rc<RefCell<dyn Animation>>>) -> Self {
+    Self { animations }
+  }
+}
+
+impl Animation for Parallel {
+  // Only the time left over after the longest animation has finished is left over
+  fn advance(&mut self, dt: f32) -> f32 {
+    self
+      .animations
+      .iter()
+      .map(|animation| {
+        let mut animation = animation.borrow_mut();
+
+        if animation.is_finished() {
+          dt
+        } else {
+          animation.advance(dt)
+        }
+      })
+      .fold(dt, f32::min)
+  }
+
+  fn is_finished(&self) -> bool {
+    self.animations.iter().all(|animation| animation.borrow().is_finished())
+  }
+
+  fn reset(&mut self) {
+    for animation in &self.animations {
+      animation.borrow_mut().reset();
+    }
+  }
+}
@@ -0,0 +1,58 @@
+use super::Animation;
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Plays the given animations one after another. The caller keeps its own references to the given animations to read
+// their values.
+pub struct Sequence {
+  animations: Vec<Rc<RefCell<dyn Animation>>>,
+}
+
+impl Debug for Sequence {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Sequence")
+      .field("len", &self.animations.len())
+      .field("is_finished", &self.is_finished())
+      .finish_non_exhaustive()
+  }
+}
+
+impl Sequence {
+  pub fn new(animations: Vec<Rc<RefCell<dyn Animation>>>) -> Self {
+    Self { animations }
+  }
+}
+
+impl Animation for Sequence {
+  fn advance(&mut self, mut dt: f32) -> f32 {
+    for animation in &self.animations {
+      let mut animation = animation.borrow_mut();
+
+      if animation.is_finished() {
+        continue;
+      }
+
+      dt = animation.advance(dt);
+
+      if !animation.is_finished() {
+        return 0f32;
+      }
+    }
+
+    dt
+  }
+
+  fn is_finished(&self) -> bool {
+    self.animations.iter().all(|animation| animation.borrow().is_finished())
+  }
+
+  fn reset(&mut self) {
+    for animation in &self.animations {
+      animation.borrow_mut().reset();
+    }
+  }
+}
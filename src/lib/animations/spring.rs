@@ -0,0 +1,131 @@
+use super::{Animation, Lerp};
+
+// Springs are simulated in fixed steps of this many seconds to stay stable regardless of the frame rate
+const STEP: f32 = 1f32 / 240f32;
+
+// Springs settle when both the distance to the end and the speed in progress per second are below this value
+const REST_THRESHOLD: f32 = 1e-3f32;
+
+// Moves from one value to another like a mass attached to a damped spring, so it can overshoot and oscillate before it
+// settles, and takes as long as the physics needs instead of a given duration
+#[derive(Clone, Debug)]
+pub struct Spring<T> {
+  from: T,
+  to: T,
+  stiffness: f32,
+  damping: f32,
+  mass: f32,
+
+  // Progress from the start value at 0 to the end value at 1, and its velocity per second
+  position: f32,
+  velocity: f32,
+
+  // Simulated time not enough for a whole step yet
+  remainder: f32,
+}
+
+impl<T: Lerp> Spring<T> {
+  pub fn new(from: T, to: T) -> Self {
+    Self {
+      from,
+      to,
+      stiffness: 170f32,
+      damping: 26f32,
+      mass: 1f32,
+      position: 0f32,
+      velocity: 0f32,
+      remainder: 0f32,
+    }
+  }
+
+  pub fn with_stiffness(mut self, stiffness: f32) -> Self {
+    // Preconditions
+    assert!(stiffness > 0f32, "stiffness must be a positive value");
+
+    self.stiffness = stiffness;
+    self
+  }
+
+  // The spring oscillates less the higher the damping is and does not overshoot from 2 * sqrt(stiffness * mass)
+  pub fn with_damping(mut self, damping: f32) -> Self {
+    // Preconditions
+    assert!(damping >= 0f32, "damping must be a non-negative value");
+
+    self.damping = damping;
+    self
+  }
+
+  pub fn with_mass(mut self, mass: f32) -> Self {
+    // Preconditions
+    assert!(mass > 0f32, "mass must be a positive value");
+
+    self.mass = mass;
+    self
+  }
+
+  pub const fn get_from(&self) -> &T {
+    &self.from
+  }
+
+  pub const fn get_to(&self) -> &T {
+    &self.to
+  }
+
+  pub const fn get_progress(&self) -> f32 {
+    self.position
+  }
+
+  pub fn get_value(&self) -> T {
+    self.from.lerp(&self.to, self.position)
+  }
+
+  fn step(&mut self) {
+    // Semi-implicit Euler integration of Hooke's law with damping
+    let force = -self.stiffness * (self.position - 1f32) - self.damping * self.velocity;
+    self.velocity += force / self.mass * STEP;
+    self.position += self.velocity * STEP;
+
+    if self.is_at_rest() {
+      self.position = 1f32;
+      self.velocity = 0f32;
+    }
+  }
+
+  fn is_at_rest(&self) -> bool {
+    (self.position - 1f32).abs() < REST_THRESHOLD && self.velocity.abs() < REST_THRESHOLD
+  }
+}
+
+impl<T: Lerp> Animation for Spring<T> {
+  fn advance(&mut self, dt: f32) -> f32 {
+    self.remainder += dt;
+
+    while self.remainder >= STEP {
+      if self.is_finished() {
+        break;
+      }
+
+      self.step();
+      self.remainder -= STEP;
+    }
+
+    if self.is_finished() {
+      // Whole steps not needed after settling are left over too
+      let leftover = dt.min(self.remainder);
+      self.remainder = 0f32;
+      return leftover;
+    }
+
+    0f32
+  }
+
+  fn is_finished(&self) -> bool {
+    self.position == 1f32 && self.velocity == 0f32
+  }
+
+  fn reset(&mut self) {
+    self.position = 0f32;
+    self.velocity = 0f32;
+    self.remainder = 0f32;
+  }
+}
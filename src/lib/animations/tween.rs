@@ -0,0 +1,137 @@
+use super::{Animation, Easing, Lerp};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Repeat {
+  // Plays the given number of times in total
+  Times(usize),
+
+  Forever,
+}
+
+impl Default for Repeat {
+  fn default() -> Self {
+    Self::Times(1)
+  }
+}
+
+// Interpolates from one value to another over the given duration in seconds along the given easing curve
+#[derive(Clone, Debug)]
+pub struct Tween<T> {
+  from: T,
+  to: T,
+  duration: f32,
+  delay: f32,
+  easing: Easing,
+  repeat: Repeat,
+
+  // Whether every other play goes backwards from the end to the start
+  yoyo: bool,
+
+  // Including the delay
+  elapsed: f32,
+}
+
+impl<T: Lerp> Tween<T> {
+  pub fn new(from: T, to: T, duration: f32) -> Self {
+    // Preconditions
+    assert!(duration >= 0f32, "duration must be a non-negative value");
+
+    Self {
+      from,
+      to,
+      duration,
+      delay: 0f32,
+      easing: Easing::Linear,
+      repeat: Repeat::default(),
+      yoyo: false,
+      elapsed: 0f32,
+    }
+  }
+
+  pub fn with_easing(mut self, easing: impl Into<Easing>) -> Self {
+    self.easing = easing.into();
+    self
+  }
+
+  // Holds the start value for the given seconds before playing
+  pub fn with_delay(mut self, delay: f32) -> Self {
+    // Preconditions
+    assert!(delay >= 0f32, "delay must be a non-negative value");
+
+    self.delay = delay;
+    self
+  }
+
+  pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+    // Preconditions
+    assert_ne!(repeat, Repeat::Times(0), "repeat must be at least once");
+
+    self.repeat = repeat;
+    self
+  }
+
+  pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+    self.yoyo = yoyo;
+    self
+  }
+
+  pub const fn get_from(&self) -> &T {
+    &self.from
+  }
+
+  pub const fn get_to(&self) -> &T {
+    &self.to
+  }
+
+  fn get_total_duration(&self) -> f32 {
+    match self.repeat {
+      Repeat::Times(times) => self.delay + self.duration * times as f32,
+      Repeat::Forever => f32::INFINITY,
+    }
+  }
+
+  // The eased progress of the current play between 0 and 1, which can go beyond them when the easing curve overshoots
+  pub fn get_progress(&self) -> f32 {
+    let (play, t) = match self.repeat {
+      // End exactly at the end of the last play
+      Repeat::Times(times) if self.is_finished() => (times - 1, 1f32),
+      _ if self.duration == 0f32 || self.elapsed < self.delay => (0, 0f32),
+      _ => {
+        let plays = (self.elapsed - self.delay) / self.duration;
+        (plays as usize, plays.fract())
+      },
+    };
+
+    let t = if self.yoyo && play % 2 == 1 { 1f32 - t } else { t };
+    self.easing.ease(t)
+  }
+
+  pub fn get_value(&self) -> T {
+    self.from.lerp(&self.to, self.get_progress())
+  }
+
+  // Plays again from the current value to the given value, which keeps the value continuous when the target changes
+  // in the middle of a play
+  pub fn retarget(&mut self, to: T) {
+    self.from = self.get_value();
+    self.to = to;
+    self.elapsed = self.delay;
+  }
+}
+
+impl<T: Lerp> Animation for Tween<T> {
+  fn advance(&mut self, dt: f32) -> f32 {
+    let total_duration = self.get_total_duration();
+    let elapsed = self.elapsed + dt;
+    self.elapsed = elapsed.min(total_duration);
+    (elapsed - total_duration).max(0f32)
+  }
+
+  fn is_finished(&self) -> bool {
+    self.elapsed >= self.get_total_duration()
+  }
+
+  fn reset(&mut self) {
+    self.elapsed = 0f32;
+  }
+}
@@ -1,15 +1,49 @@
+use super::SpriteAtlas;
 use sdl2::{
   mixer::{Chunk, LoaderRWops},
   rwops::RWops,
 };
-use std::{collections::HashMap, fs};
+use skia_safe::{Data, FontMgr, Image, RuntimeEffect, Typeface};
+use std::{collections::HashMap, fs, rc::Rc};
 
 pub fn load_sounds(dir_path: &str) -> HashMap<String, Chunk> {
-  load_sounds_with_base_dir(dir_path, dir_path)
+  load_assets_with_base_dir(dir_path, dir_path, &|path| {
+    RWops::from_file(path, "rb").unwrap().load_wav().unwrap()
+  })
 }
 
-fn load_sounds_with_base_dir(base_dir_path: &str, dir_path: &str) -> HashMap<String, Chunk> {
-  let mut sounds = HashMap::new();
+pub fn load_fonts(dir_path: &str) -> HashMap<String, Typeface> {
+  let font_mgr = FontMgr::new();
+
+  load_assets_with_base_dir(dir_path, dir_path, &|path| {
+    font_mgr.new_from_data(&fs::read(path).unwrap(), None).unwrap()
+  })
+}
+
+// Decodes PNG and JPEG files into images that Skia can draw directly
+pub fn load_images(dir_path: &str) -> HashMap<String, Image> {
+  load_assets_with_base_dir(dir_path, dir_path, &|path| {
+    Image::from_encoded(Data::new_copy(&fs::read(path).unwrap())).unwrap()
+  })
+}
+
+// Each atlas is a JSON descriptor referring to one of the given images by name
+pub fn load_atlases(dir_path: &str, images: &HashMap<String, Image>) -> HashMap<String, Rc<SpriteAtlas>> {
+  load_assets_with_base_dir(dir_path, dir_path, &|path| {
+    Rc::new(SpriteAtlas::from_json(&fs::read_to_string(path).unwrap(), images))
+  })
+}
+
+// Compiles SkSL files into effects that ShaderNode can draw
+pub fn load_effects(dir_path: &str) -> HashMap<String, RuntimeEffect> {
+  load_assets_with_base_dir(dir_path, dir_path, &|path| {
+    RuntimeEffect::make_for_shader(fs::read_to_string(path).unwrap(), None).unwrap()
+  })
+}
+
+// Assets are named by their paths relative to the base directory without file extensions
+fn load_assets_with_base_dir<T>(base_dir_path: &str, dir_path: &str, load: &impl Fn(&str) -> T) -> HashMap<String, T> {
+  let mut assets = HashMap::new();
 
   for entry in fs::read_dir(dir_path).unwrap() {
     let entry = entry.unwrap();
@@ -17,16 +51,16 @@ fn load_sounds_with_base_dir(base_dir_path: &str, dir_path: &str) -> HashMap<Str
     let path = path.to_str().unwrap();
 
     if entry.file_type().unwrap().is_dir() {
-      sounds.extend(load_sounds_with_base_dir(base_dir_path, path));
+      assets.extend(load_assets_with_base_dir(base_dir_path, path, load));
     } else {
       let relative_path = path.strip_prefix(base_dir_path).unwrap();
 
-      sounds.insert(
+      assets.insert(
         relative_path[..relative_path.rfind('.').unwrap_or(relative_path.len())].to_owned(),
-        RWops::from_file(path, "rb").unwrap().load_wav().unwrap(),
+        load(path),
       );
     }
   }
 
-  sounds
+  assets
 }
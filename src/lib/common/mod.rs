@@ -2,7 +2,11 @@ pub mod asset_loader;
 pub mod clock;
 pub mod sharable;
 pub mod sparse_set;
+pub mod sprite_atlas;
+pub mod velocity_tracker;
 
 pub use clock::Clock;
 pub use sharable::Sharable;
 pub use sparse_set::SparseSet;
+pub use sprite_atlas::{SpriteAnimation, SpriteAtlas};
+pub use velocity_tracker::VelocityTracker;
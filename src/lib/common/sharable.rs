@@ -6,6 +6,16 @@ pub enum Sharable<T> {
   Shared(Rc<RefCell<T>>),
 }
 
+impl<T> Sharable<T> {
+  // Wraps an owned value to share it, or returns the shared one as is
+  pub fn into_shared(self) -> Rc<RefCell<T>> {
+    match self {
+      Sharable::Owned(value) => Rc::new(RefCell::new(value)),
+      Sharable::Shared(value) => value,
+    }
+  }
+}
+
 impl<T> From<T> for Sharable<T> {
   fn from(value: T) -> Self {
     Sharable::Owned(value)
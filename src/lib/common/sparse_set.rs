@@ -1,6 +1,5 @@
 use std::fmt::Debug;
-use tinyrand::RandRange;
-use tinyrand_std::thread_rand;
+use tinyrand::{Rand, RandRange};
 
 #[derive(Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SparseSet<T> {
@@ -25,8 +24,8 @@ impl<T: TryFrom<usize, Error = impl Debug> + Into<usize> + Copy> SparseSet<T> {
     self.sparse[key] = Some((self.dense.len() - 1).try_into().unwrap());
   }
 
-  pub fn remove_random_key(&mut self) -> T {
-    let key = self.dense[thread_rand().next_range(0..self.dense.len())];
+  pub fn remove_random_key(&mut self, rng: &mut impl Rand) -> T {
+    let key = self.dense[rng.next_range(0..self.dense.len())];
     self.remove(key);
     key
   }
@@ -0,0 +1,104 @@
+use serde::Deserialize;
+use skia_safe::{IRect, Image};
+use std::{
+  collections::HashMap,
+  fmt::{self, Debug, Formatter},
+};
+
+// A named sequence of frames in a sprite atlas
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, Deserialize)]
+pub struct SpriteAnimation {
+  pub frames: Vec<String>,
+
+  // In seconds
+  pub frame_duration: f32,
+
+  #[serde(default)]
+  pub looping: bool,
+}
+
+// The JSON descriptor of a sprite atlas, e.g.
+// {
+//   "image": "snake",
+//   "frames": { "head": [0, 0, 16, 16], "head_blink": [16, 0, 16, 16] },
+//   "animations": { "blink": { "frames": ["head", "head_blink"], "frame_duration": 0.1, "looping": true } }
+// }
+// where image is the name of an image in assets/images/ and each frame is [x, y, width, height] in pixels.
+#[derive(Deserialize)]
+struct SpriteAtlasDesc {
+  image: String,
+  frames: HashMap<String, [i32; 4]>,
+
+  #[serde(default)]
+  animations: HashMap<String, SpriteAnimation>,
+}
+
+// Maps named frames to rects in one image so that sprites can share a texture
+#[derive(Clone)]
+pub struct SpriteAtlas {
+  image: Image,
+  frames: HashMap<String, IRect>,
+  animations: HashMap<String, SpriteAnimation>,
+}
+
+impl Debug for SpriteAtlas {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("SpriteAtlas")
+      .field("frames", &self.frames)
+      .field("animations", &self.animations)
+      .finish_non_exhaustive()
+  }
+}
+
+impl SpriteAtlas {
+  pub fn new(image: Image, frames: HashMap<String, IRect>, animations: HashMap<String, SpriteAnimation>) -> Self {
+    // Preconditions
+    for animation in animations.values() {
+      assert!(
+        animation.frame_duration > 0f32,
+        "frame_duration must be a positive value"
+      );
+
+      for frame in &animation.frames {
+        assert!(
+          frames.contains_key(frame),
+          "frames must contain {frame} used by animations"
+        );
+      }
+    }
+
+    Self {
+      image,
+      frames,
+      animations,
+    }
+  }
+
+  // Panics if the JSON is invalid or the image it refers to is not in the given images
+  pub fn from_json(json: &str, images: &HashMap<String, Image>) -> Self {
+    let desc: SpriteAtlasDesc = serde_json::from_str(json).unwrap();
+
+    Self::new(
+      images[&desc.image].clone(),
+      desc
+        .frames
+        .into_iter()
+        .map(|(name, [x, y, width, height])| (name, IRect::from_xywh(x, y, width, height)))
+        .collect(),
+      desc.animations,
+    )
+  }
+
+  pub const fn get_image(&self) -> &Image {
+    &self.image
+  }
+
+  pub fn get_frame(&self, name: &str) -> Option<IRect> {
+    self.frames.get(name).copied()
+  }
+
+  pub fn get_animation(&self, name: &str) -> Option<&SpriteAnimation> {
+    self.animations.get(name)
+  }
+}
@@ -0,0 +1,51 @@
+use std::collections::VecDeque;
+
+// Velocity is the average over the positions the pointer moved through within this many seconds. Events only arrive
+// between frames, which can span several ticks, so the motion of a single tick says nothing about velocity.
+const VELOCITY_WINDOW: f32 = 0.1f32;
+
+// Tracks the velocity of a pointer from the times and positions it moved through
+#[derive(Clone, Debug, PartialEq)]
+pub struct VelocityTracker {
+  // Oldest first, which always has the sample at which tracking starts
+  samples: VecDeque<(f32, (f32, f32))>,
+}
+
+impl VelocityTracker {
+  pub fn new(time: f32, position: (f32, f32)) -> Self {
+    Self {
+      samples: VecDeque::from([(time, position)]),
+    }
+  }
+
+  pub fn add_sample(&mut self, time: f32, position: (f32, f32)) {
+    self.samples.push_back((time, position));
+    self.forget_samples(time);
+  }
+
+  // Keeps the last sample before the velocity window, which is where the pointer was when the window starts
+  pub fn forget_samples(&mut self, time: f32) {
+    while self
+      .samples
+      .get(1)
+      .is_some_and(|&(sample_time, _)| time - sample_time >= VELOCITY_WINDOW)
+    {
+      self.samples.pop_front();
+    }
+  }
+
+  // In pixels per second
+  pub fn get_velocity(&self, time: f32) -> (f32, f32) {
+    let (Some(&(sample_time, from)), Some(&(_, to))) = (self.samples.front(), self.samples.back()) else {
+      return (0f32, 0f32);
+    };
+
+    let elapsed = (time - sample_time).min(VELOCITY_WINDOW);
+
+    if elapsed <= 0f32 {
+      return (0f32, 0f32);
+    }
+
+    ((to.0 - from.0) / elapsed, (to.1 - from.1) / elapsed)
+  }
+}
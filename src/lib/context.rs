@@ -1,10 +1,33 @@
-use crate::{common::asset_loader, Engine};
-use sdl2::mixer::{Channel, Chunk};
-use std::{cell::RefCell, collections::HashMap};
+use crate::{
+  common::{asset_loader, SpriteAtlas},
+  input::{Binding, Controllers, InputMap, InputState},
+  models::Box2D,
+  nodes::text_node,
+  Engine,
+};
+use sdl2::{
+  event::Event,
+  mixer::{Channel, Chunk},
+  GameControllerSubsystem,
+};
+use skia_safe::{textlayout::FontCollection, Image, RuntimeEffect, Typeface};
+use std::{cell::RefCell, collections::HashMap, path::Path, rc::Rc};
+use tinyrand::{Seeded, StdRand};
 
+#[derive(Default)]
 pub struct Context {
   engine: Engine,
   sounds: HashMap<String, Chunk>,
+  fonts: HashMap<String, Typeface>,
+  font_collection: Option<FontCollection>,
+  images: HashMap<String, Image>,
+  atlases: HashMap<String, Rc<SpriteAtlas>>,
+  effects: HashMap<String, RuntimeEffect>,
+  seed: u64,
+  rng: StdRand,
+  input_map: InputMap,
+  input_state: InputState,
+  controllers: Controllers,
 }
 
 impl Context {
@@ -12,18 +35,203 @@ impl Context {
     self.sounds = asset_loader::load_sounds("assets/sounds/");
   }
 
+  // Fonts are optional, so an app without a fonts directory draws text with the default typeface
+  pub(super) fn init_fonts(&mut self) {
+    const FONTS_DIR_PATH: &str = "assets/fonts/";
+
+    if Path::new(FONTS_DIR_PATH).is_dir() {
+      self.fonts = asset_loader::load_fonts(FONTS_DIR_PATH);
+    }
+
+    self.font_collection = Some(text_node::make_font_collection(self.fonts.values()));
+  }
+
+  // Images are optional like fonts. Sprite atlases are loaded together since they are made of images.
+  pub(super) fn init_images(&mut self) {
+    const IMAGES_DIR_PATH: &str = "assets/images/";
+    const ATLASES_DIR_PATH: &str = "assets/atlases/";
+
+    if Path::new(IMAGES_DIR_PATH).is_dir() {
+      self.images = asset_loader::load_images(IMAGES_DIR_PATH);
+    }
+
+    if Path::new(ATLASES_DIR_PATH).is_dir() {
+      self.atlases = asset_loader::load_atlases(ATLASES_DIR_PATH, &self.images);
+    }
+  }
+
+  // Shaders are optional like fonts
+  pub(super) fn init_effects(&mut self) {
+    const SHADERS_DIR_PATH: &str = "assets/shaders/";
+
+    if Path::new(SHADERS_DIR_PATH).is_dir() {
+      self.effects = asset_loader::load_effects(SHADERS_DIR_PATH);
+    }
+  }
+
+  pub(super) fn init_rng(&mut self, seed: u64) {
+    self.seed = seed;
+    self.rng = StdRand::seed(seed);
+  }
+
+  pub(super) fn init_input(&mut self, input_map: InputMap) {
+    self.input_map = input_map;
+  }
+
+  pub(super) fn init_controllers(&mut self, subsystem: GameControllerSubsystem) {
+    self.controllers.init(subsystem);
+  }
+
+  // Controllers are connected before the events of the view tree are dispatched, so that they can be queried by then
+  pub(super) fn on_input_event(&mut self, event: &Event) {
+    self.controllers.on_event(event);
+    self.input_state.on_event(event, self.controllers.get_dead_zone());
+  }
+
+  // Actions are only just pressed or released during the first tick after the events pressing or releasing them
+  pub(super) fn end_input_tick(&mut self) {
+    self.input_state.end_tick();
+  }
+
   pub(super) fn get_engine(&mut self) -> &mut Engine {
     &mut self.engine
   }
 
   pub fn play_sound(&self, name: &str) {
-    Channel::all().play(&self.sounds[name], 0).unwrap();
+    // Sounds are only loaded when the app plays audio, so stay silent otherwise
+    if let Some(sound) = self.sounds.get(name) {
+      Channel::all().play(sound, 0).unwrap();
+    }
+  }
+
+  // Typefaces are named by their paths relative to assets/fonts/ without file extensions, e.g. "DejaVuSans"
+  pub fn get_typeface(&self, name: &str) -> Option<Typeface> {
+    self.fonts.get(name).cloned()
+  }
+
+  // The fonts of the system and assets/fonts/ for text nodes, which share the text shaped with it. None until the app
+  // loads fonts.
+  pub fn get_font_collection(&self) -> Option<FontCollection> {
+    self.font_collection.clone()
+  }
+
+  // Images are named by their paths relative to assets/images/ without file extensions, e.g. "favicon"
+  pub fn get_image(&self, name: &str) -> Option<Image> {
+    self.images.get(name).cloned()
+  }
+
+  // Atlases are named by their paths relative to assets/atlases/ without file extensions
+  pub fn get_atlas(&self, name: &str) -> Option<Rc<SpriteAtlas>> {
+    self.atlases.get(name).cloned()
+  }
+
+  // Effects are named by their paths relative to assets/shaders/ without file extensions
+  pub fn get_effect(&self, name: &str) -> Option<RuntimeEffect> {
+    self.effects.get(name).cloned()
+  }
+
+  // Makes the child of the state being called back again on the next traversal. Only needed when the state changes
+  // anything that its make() depends on.
+  pub fn mark_dirty(&mut self) {
+    self.engine.mark_dirty();
+  }
+
+  pub fn set_state(&mut self, update: impl FnOnce()) {
+    update();
+    self.mark_dirty();
+  }
+
+  // Stops the event being dispatched from reaching any other view after the one being called back
+  pub fn stop_propagation(&mut self) {
+    self.engine.stop_propagation();
+  }
+
+  // The rect of the view being called back by an event or tick, which is resolved by the last layout pass
+  pub const fn get_rect(&self) -> Option<Box2D> {
+    self.engine.get_current_rect()
+  }
+
+  // The position of the pointer of the event being dispatched, in the same space as the rect of the view being called
+  // back. Touch positions are in pixels here unlike in touch events.
+  pub const fn get_pointer_position(&self) -> Option<(f32, f32)> {
+    self.engine.get_current_pointer_position()
+  }
+
+  // Focuses the stateful layout being called back, which gets key events before the rest of the view tree from then on
+  pub fn request_focus(&mut self) {
+    self.engine.request_focus();
+  }
+
+  // Leaves no view focused, so that key events reach every view again
+  pub fn unfocus(&mut self) {
+    self.engine.unfocus();
+  }
+
+  // Whether the stateful layout being called back has focus
+  pub fn has_focus(&self) -> bool {
+    self.engine.has_focus()
+  }
+
+  // Moves focus the same way as pressing Tab, or Shift+Tab when backward
+  pub fn focus_next(&mut self, is_backward: bool) {
+    self.engine.focus_next(is_backward);
+  }
+
+  pub const fn get_input_map(&self) -> &InputMap {
+    &self.input_map
+  }
+
+  // Rebinding takes effect immediately, e.g. from a controls menu
+  pub fn get_input_map_mut(&mut self) -> &mut InputMap {
+    &mut self.input_map
+  }
+
+  pub const fn get_input_state(&self) -> &InputState {
+    &self.input_state
+  }
+
+  // Whether any binding of the given action is held down
+  pub fn is_action_pressed(&self, action: &str) -> bool {
+    self.is_any_binding(action, |binding| self.input_state.is_pressed(binding))
+  }
+
+  // Whether any binding of the given action has been pressed since the previous tick. Query this from State::tick().
+  pub fn is_action_just_pressed(&self, action: &str) -> bool {
+    self.is_any_binding(action, |binding| self.input_state.is_just_pressed(binding))
+  }
+
+  // Whether any binding of the given action has been released since the previous tick
+  pub fn is_action_just_released(&self, action: &str) -> bool {
+    self.is_any_binding(action, |binding| self.input_state.is_just_released(binding))
+  }
+
+  // The polled state of the connected game controllers
+  pub const fn get_controllers(&self) -> &Controllers {
+    &self.controllers
+  }
+
+  pub fn get_controllers_mut(&mut self) -> &mut Controllers {
+    &mut self.controllers
+  }
+
+  fn is_any_binding(&self, action: &str, predicate: impl Fn(Binding) -> bool) -> bool {
+    self
+      .input_map
+      .get_bindings(action)
+      .iter()
+      .any(|&binding| predicate(binding))
+  }
+
+  // Replaying with the same seed produces the same sequence of random numbers
+  pub const fn get_seed(&self) -> u64 {
+    self.seed
+  }
+
+  pub fn get_rng(&mut self) -> &mut StdRand {
+    &mut self.rng
   }
 }
 
 thread_local! {
-  pub static CONTEXT: RefCell<Context> = RefCell::new(Context {
-    engine: Engine::default(),
-    sounds: HashMap::new(),
-  })
+  pub static CONTEXT: RefCell<Context> = RefCell::new(Context::default())
 }
@@ -1,236 +1,1155 @@
 use crate::{
   common::Sharable,
-  layouts::{stateful_layout::State, MultiChildLayout, StatefulLayout, StatelessLayout},
-  models::Box2D,
+  layouts::{stateful_layout::State, Children, MultiChildLayout, StatefulLayout, StatelessLayout},
+  models::{Box2D, Constraints, Direction, PointerEvent, ViewId},
   Context, View,
 };
-use sdl2::event::Event;
-use skia_safe::Canvas;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use sdl2::{
+  event::Event,
+  keyboard::{Keycode, Mod},
+};
+use skia_safe::{Canvas, Matrix};
+use std::{
+  borrow::Cow,
+  cell::RefCell,
+  collections::{HashMap, HashSet},
+  mem,
+  rc::Rc,
+};
 
 const NO_CONSTRAINT: Box2D = Box2D {
   position: (0f32, 0f32),
   size: (f32::MAX, f32::MAX),
 };
 
+pub(super) const TICK_DT: f32 = 1f32 / 120f32; // 120 ticks per second
+const MAX_TICKS_PER_FRAME: usize = 8;
+
+// Where a view is placed in the view tree, which identifies the view across frames
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Slot {
+  parent: ViewId,
+  index: usize,
+}
+
+impl Slot {
+  const ROOT: Slot = Slot::new(ViewId::ROOT, 0);
+
+  const fn new(parent: ViewId, index: usize) -> Slot {
+    Slot { parent, index }
+  }
+
+  fn get_id(self) -> ViewId {
+    self.parent.make_child(self.index)
+  }
+
+  fn get_stateful_id(self, layout: &dyn StatefulLayout) -> ViewId {
+    // A keyed layout keeps its state when reordered among its siblings. Also include the type of the layout so that
+    // a different layout placed at the same slot never picks up the state of the previous one.
+    match layout.get_key() {
+      Some(key) => self.parent.make_child((key, layout.get_type_name())),
+      None => self.parent.make_child((self.index, layout.get_type_name())),
+    }
+  }
+}
+
+// A state of a stateful layout in the view tree together with the child it made last time
+struct Mount {
+  state: Rc<RefCell<dyn State>>,
+  child: Option<Rc<RefCell<View>>>,
+  constraint: Box2D,
+  is_dirty: bool,
+}
+
+// A stateful layout found focusable by the last layout pass
+struct Focusable {
+  id: ViewId,
+
+  // The innermost focus scope containing it, which is the root without any
+  scope: ViewId,
+
+  // Ids of it and its ancestors, which key events are dispatched to while it has focus
+  path: Vec<ViewId>,
+}
+
 #[derive(Default)]
 pub(super) struct Engine {
-  state_map: HashMap<String, Rc<RefCell<dyn State>>>,
+  state_map: HashMap<ViewId, Mount>,
+
+  // Ids of stateful layouts visited since the last sweep, the states of other layouts are no longer in the view tree
+  visited_ids: HashSet<ViewId>,
+
+  // Id of the stateful layout whose state is being called back
+  current_id: Option<ViewId>,
+
+  // Rects of views resolved by the last layout pass, which are reused by event dispatch, ticking and drawing
+  rect_map: HashMap<ViewId, Box2D>,
+
+  // Sizes of views measured in the current layout pass together with the constraints they are measured with
+  size_map: HashMap<ViewId, (Constraints, (f32, f32))>,
+
+  // The rect of the whole view tree given to the last layout pass
+  root_rect: Box2D,
+
+  // Where the mouse has moved to last time in the root space
+  mouse_position: Option<(f32, f32)>,
+
+  // Ids of views under the pointer of the event being dispatched
+  hit_ids: HashSet<ViewId>,
+
+  // Ids of views under the mouse since it has moved last time
+  hovered_ids: HashSet<ViewId>,
+
+  // Ids of views pressed by the pointer until it is released
+  captured_ids: HashSet<ViewId>,
+
+  is_propagation_stopped: bool,
+
+  // The rect and pointer position of the view being called back
+  current_rect: Option<Box2D>,
+  current_pointer_position: Option<(f32, f32)>,
+
+  // Ids of the views being arranged and the focus scopes among them, from the root to the view being arranged
+  arrange_path: Vec<ViewId>,
+  scope_path: Vec<ViewId>,
+
+  // In the order of the view tree, which is the order of focus traversal
+  focus_chain: Vec<Focusable>,
+
+  focused_id: Option<ViewId>,
+
+  // The focus which the states are told about last time. Focus changes are told at the end of each pass over the view
+  // tree, since the state requesting focus is being called back then.
+  notified_focused_id: Option<ViewId>,
+
+  // Ids of the focused view and its ancestors, which the key event being dispatched goes down first. The focused view
+  // passes it on to its descendants.
+  focus_path: Option<Vec<ViewId>>,
+
+  // The focus path which the key event being dispatched has gone down already. The event then falls back to the rest
+  // of the view tree without calling back the views on it again.
+  reached_path: Option<Vec<ViewId>>,
 }
 
 impl Engine {
-  pub(super) fn draw_view(&mut self, view: &mut View, canvas: &Canvas, constraint: Box2D) {
+  pub(super) fn on_event_in_root(child: &mut Sharable<View>, context: &mut Context, event: &Event) {
+    context.on_input_event(event);
+    let engine = context.get_engine();
+    engine.is_propagation_stopped = false;
+    engine.hit_ids.clear();
+
+    if let Event::MouseMotion { x, y, .. } = *event {
+      engine.mouse_position = Some((x as _, y as _));
+    }
+
+    // Key events reach the focused view and its ancestors first while any view has focus
+    engine.focus_path = match event {
+      Event::KeyDown { .. } | Event::KeyUp { .. } | Event::TextInput { .. } | Event::TextEditing { .. } => engine
+        .focused_id
+        .and_then(|id| engine.focus_chain.iter().find(|focusable| focusable.id == id))
+        .map(|focusable| focusable.path.clone()),
+      _ => None,
+    };
+
+    let point = engine.get_pointer_position(event);
+    Engine::visit(child, |child| {
+      Engine::on_event(child, Slot::ROOT, context, event, point, false)
+    });
+
+    // Keys not handled down the focus path reach the rest of the view tree, e.g. for shortcuts of the whole app
+    let engine = context.get_engine();
+
+    if let Some(focus_path) = engine.focus_path.take() {
+      if !engine.is_propagation_stopped {
+        engine.reached_path = Some(focus_path);
+
+        Engine::visit(child, |child| {
+          Engine::on_event(child, Slot::ROOT, context, event, point, false)
+        });
+
+        context.get_engine().reached_path = None;
+      }
+    }
+
+    let engine = context.get_engine();
+
+    match *event {
+      Event::MouseMotion { .. } => engine.hovered_ids = mem::take(&mut engine.hit_ids),
+
+      // Keep dispatching pointer events to the pressed views until released, even after the pointer moves off them
+      Event::MouseButtonDown { .. } | Event::FingerDown { .. } => engine.captured_ids = mem::take(&mut engine.hit_ids),
+      Event::MouseButtonUp { .. } | Event::FingerUp { .. } => engine.captured_ids.clear(),
+
+      // Keys not handled by the focused view traverse focus
+      Event::KeyDown {
+        keycode: Some(keycode),
+        keymod,
+        ..
+      } if !engine.is_propagation_stopped => match keycode {
+        Keycode::Tab => engine.focus_next(keymod.intersects(Mod::LSHIFTMOD) || keymod.intersects(Mod::RSHIFTMOD)),
+        Keycode::Up => engine.focus_in_direction(Direction::Up),
+        Keycode::Right => engine.focus_in_direction(Direction::Right),
+        Keycode::Down => engine.focus_in_direction(Direction::Down),
+        Keycode::Left => engine.focus_in_direction(Direction::Left),
+        _ => {},
+      },
+      _ => {},
+    }
+
+    Engine::notify_focus(context);
+  }
+
+  pub(super) fn advance_root(child: &mut Sharable<View>, context: &mut Context, mut dt_left: f32) -> usize {
+    // Limit the number of ticks per frame to avoid the spiral of death when a frame takes too long
+    let mut ticks_left = MAX_TICKS_PER_FRAME;
+
+    while ticks_left > 0 && dt_left > 0f32 {
+      let dt = dt_left.min(TICK_DT);
+      Engine::tick_in_root(child, context, dt);
+      dt_left -= dt;
+      ticks_left -= 1;
+    }
+
+    MAX_TICKS_PER_FRAME - ticks_left
+  }
+
+  pub(super) fn tick_in_root(child: &mut Sharable<View>, context: &mut Context, dt: f32) {
+    Engine::visit(child, |child| Engine::tick(child, Slot::ROOT, context, dt));
+    context.end_input_tick();
+    Engine::notify_focus(context);
+  }
+
+  // Resolves the rect of each view in the given view tree which fills the given rect. Call this before drawing.
+  pub(super) fn layout_root(child: &mut Sharable<View>, context: &mut Context, rect: Box2D) {
+    let engine = context.get_engine();
+    engine.rect_map.clear();
+    engine.size_map.clear();
+    engine.root_rect = rect;
+    engine.focus_chain.clear();
+
+    Engine::visit(child, |child| Engine::arrange(child, Slot::ROOT, context, rect));
+    Engine::notify_focus(context);
+  }
+
+  pub(super) fn draw_root(child: &mut Sharable<View>, context: &mut Context, canvas: &Canvas, constraint: Box2D) {
+    Engine::visit(child, |child| {
+      Engine::draw_view(child, Slot::ROOT, context, canvas, constraint)
+    });
+  }
+
+  pub(crate) fn measure_child(
+    child: &mut Sharable<View>,
+    parent: ViewId,
+    index: usize,
+    context: &mut Context,
+    constraints: Constraints,
+  ) -> (f32, f32) {
+    Engine::visit(child, |child| {
+      Engine::measure(child, Slot::new(parent, index), context, constraints)
+    })
+  }
+
+  fn visit<R>(view: &mut Sharable<View>, visitor: impl FnOnce(&mut View) -> R) -> R {
     match view {
-      View::StatelessLayout(layout) => self.draw_stateless_layout(&mut **layout, canvas, constraint),
-      View::StatefulLayout(layout) => self.draw_stateful_layout(&mut **layout, canvas, constraint),
-      View::MultiChildLayout(layout) => self.draw_multi_child_layout(&mut **layout, canvas, constraint),
-      View::Node(node) => node.draw(canvas, constraint),
+      Sharable::Owned(view) => visitor(view),
+      Sharable::Shared(view) => visitor(&mut view.borrow_mut()),
     }
   }
 
-  fn draw_stateless_layout(&mut self, layout: &mut dyn StatelessLayout, canvas: &Canvas, constraint: Box2D) {
-    match layout.make(constraint) {
-      Some(Sharable::Owned(mut child)) => {
-        layout.pre_draw(canvas, constraint);
-        self.draw_view(&mut child, canvas, constraint);
-        layout.post_draw(canvas, constraint);
-      },
-      Some(Sharable::Shared(child)) => {
-        layout.pre_draw(canvas, constraint);
-        self.draw_view(&mut child.borrow_mut(), canvas, constraint);
-        layout.post_draw(canvas, constraint);
-      },
-      None => {},
+  fn get_view_id(view: &View, slot: Slot) -> ViewId {
+    match view {
+      View::StatefulLayout(layout) => slot.get_stateful_id(&**layout),
+      _ => slot.get_id(),
     }
   }
 
-  fn draw_stateful_layout(&mut self, layout: &mut dyn StatefulLayout, canvas: &Canvas, constraint: Box2D) {
-    let state = self
+  // Returns the rect resolved by the last layout pass, or the given rect if the view has not been laid out yet
+  fn get_rect(id: ViewId, context: &mut Context, default: Box2D) -> Box2D {
+    context.get_engine().rect_map.get(&id).copied().unwrap_or(default)
+  }
+
+  // Drops the states of stateful layouts which are not visited since the last sweep. Call this at the end of each
+  // frame.
+  pub(super) fn sweep(context: &mut Context) {
+    let engine = context.get_engine();
+    let visited_ids = mem::take(&mut engine.visited_ids);
+
+    let unmounted_ids = engine
       .state_map
-      .entry(layout.get_key().to_owned())
-      .or_insert_with(|| layout.make_state())
-      .borrow();
+      .keys()
+      .filter(|id| !visited_ids.contains(id))
+      .copied()
+      .collect::<Vec<_>>();
 
-    match state.make(constraint) {
-      Some(Sharable::Owned(mut child)) => {
-        state.pre_draw(canvas, constraint);
-        drop(state);
-        self.draw_view(&mut child, canvas, constraint);
+    // An unmounted view can no longer have focus
+    if engine.focused_id.is_some_and(|id| unmounted_ids.contains(&id)) {
+      engine.focused_id = None;
+    }
+
+    if engine.notified_focused_id.is_some_and(|id| unmounted_ids.contains(&id)) {
+      engine.notified_focused_id = None;
+    }
+
+    let unmounted_states = unmounted_ids
+      .into_iter()
+      .filter_map(|id| engine.state_map.remove(&id).map(|mount| (id, mount.state)))
+      .collect::<Vec<_>>();
+
+    // Drop the mutable borrow of engine from the given context here, because on_unmount(context) call might mutably
+    // borrow this engine
+    for (id, state) in unmounted_states {
+      Engine::call_back(id, context, |context| state.borrow_mut().on_unmount(context));
+    }
+  }
+
+  pub(super) fn mark_dirty(&mut self) {
+    // Preconditions
+    debug_assert!(
+      self.current_id.is_some(),
+      "mark_dirty() must be called while calling back a state"
+    );
+
+    if let Some(mount) = self.current_id.and_then(|id| self.state_map.get_mut(&id)) {
+      mount.is_dirty = true;
+    }
+  }
+
+  pub(super) fn request_focus(&mut self) {
+    // Preconditions
+    debug_assert!(
+      self.current_id.is_some(),
+      "request_focus() must be called while calling back a state"
+    );
+
+    if self.current_id.is_some() {
+      self.focused_id = self.current_id;
+    }
+  }
+
+  pub(super) fn unfocus(&mut self) {
+    self.focused_id = None;
+  }
+
+  pub(super) fn has_focus(&self) -> bool {
+    self.current_id.is_some() && self.current_id == self.focused_id
+  }
+
+  // The focusable views which focus can move to from the focused view, which are in the same focus scope
+  fn get_focus_candidates(&self) -> Vec<ViewId> {
+    let scope = self
+      .focused_id
+      .and_then(|id| self.focus_chain.iter().find(|focusable| focusable.id == id))
+      .map(|focusable| focusable.scope);
+
+    self
+      .focus_chain
+      .iter()
+      .filter(|focusable| scope.is_none() || scope == Some(focusable.scope))
+      .map(|focusable| focusable.id)
+      .collect()
+  }
+
+  // Moves focus to the next or previous focusable view in the order of the view tree, wrapping around within the focus
+  // scope of the focused view
+  pub(super) fn focus_next(&mut self, is_backward: bool) {
+    let candidates = self.get_focus_candidates();
+
+    if candidates.is_empty() {
+      return;
+    }
+
+    let index = match self
+      .focused_id
+      .and_then(|id| candidates.iter().position(|&candidate| candidate == id))
+    {
+      Some(index) if is_backward => (index + candidates.len() - 1) % candidates.len(),
+      Some(index) => (index + 1) % candidates.len(),
+      None if is_backward => candidates.len() - 1,
+      None => 0,
+    };
+
+    self.focused_id = Some(candidates[index]);
+  }
+
+  // Moves focus to the nearest focusable view in the given direction from the focused view within its focus scope. Views
+  // off to the side are farther than views straight ahead.
+  pub(super) fn focus_in_direction(&mut self, direction: Direction) {
+    let Some(from) = self.focused_id.and_then(|id| self.rect_map.get(&id)) else {
+      return;
+    };
+
+    let from = from.get_center();
+
+    let nearest_id = self
+      .get_focus_candidates()
+      .into_iter()
+      .filter(|&id| Some(id) != self.focused_id)
+      .filter_map(|id| {
+        let to = self.rect_map.get(&id)?.get_center();
+
+        let (ahead, aside) = match direction {
+          Direction::Up => (from.1 - to.1, to.0 - from.0),
+          Direction::Right => (to.0 - from.0, to.1 - from.1),
+          Direction::Down => (to.1 - from.1, to.0 - from.0),
+          Direction::Left => (from.0 - to.0, to.1 - from.1),
+        };
+
+        (ahead > 0f32).then_some((id, ahead + aside.abs() * 2f32))
+      })
+      .min_by(|(_, a), (_, b)| a.total_cmp(b))
+      .map(|(id, _)| id);
+
+    if nearest_id.is_some() {
+      self.focused_id = nearest_id;
+    }
+  }
 
-        self
+  // Tells the states losing and gaining focus since last time about that
+  fn notify_focus(context: &mut Context) {
+    loop {
+      let engine = context.get_engine();
+
+      if engine.focused_id == engine.notified_focused_id {
+        return;
+      }
+
+      let lost_id = mem::replace(&mut engine.notified_focused_id, engine.focused_id);
+      let gained_id = engine.focused_id;
+
+      for (id, is_gained) in [(lost_id, false), (gained_id, true)] {
+        let Some(id) = id else {
+          continue;
+        };
+
+        let Some(state) = context
+          .get_engine()
           .state_map
-          .get(&layout.get_key().to_owned())
-          .unwrap()
-          .borrow()
-          .post_draw(canvas, constraint);
+          .get(&id)
+          .map(|mount| Rc::clone(&mount.state))
+        else {
+          continue;
+        };
+
+        Engine::call_back(id, context, |context| {
+          if is_gained {
+            state.borrow_mut().on_focus_gained(context);
+          } else {
+            state.borrow_mut().on_focus_lost(context);
+          }
+        });
+      }
+    }
+  }
+
+  // Lets the given callback mark the state of the given stateful layout dirty
+  fn call_back(id: ViewId, context: &mut Context, callback: impl FnOnce(&mut Context)) {
+    let prev_id = context.get_engine().current_id.replace(id);
+    callback(context);
+    context.get_engine().current_id = prev_id;
+  }
+
+  fn get_state(layout: &mut dyn StatefulLayout, id: ViewId, context: &mut Context) -> Rc<RefCell<dyn State>> {
+    let engine = context.get_engine();
+    engine.visited_ids.insert(id);
+
+    if let Some(mount) = engine.state_map.get(&id) {
+      let state = Rc::clone(&mount.state);
+
+      if let Some(props) = layout.take_props() {
+        Engine::call_back(id, context, |context| state.borrow_mut().on_update(context, props));
+      }
+
+      return state;
+    }
+
+    // Drop the mutable borrow of engine from the given context here, because make_state(context) call might mutably
+    // borrow this engine
+    let state = layout.make_state(context);
+
+    context.get_engine().state_map.insert(
+      id,
+      Mount {
+        state: Rc::clone(&state),
+        child: None,
+        constraint: NO_CONSTRAINT,
+        is_dirty: true,
       },
-      Some(Sharable::Shared(child)) => {
-        state.pre_draw(canvas, constraint);
-        drop(state);
-        self.draw_view(&mut child.borrow_mut(), canvas, constraint);
+    );
 
-        self
-          .state_map
-          .get(&layout.get_key().to_owned())
-          .unwrap()
+    Engine::call_back(id, context, |context| state.borrow_mut().on_mount(context));
+    state
+  }
+
+  // Only calls make() of the state when it is dirty or the given constraint has changed, otherwise reuses the child
+  // made last time. Without the given constraint, any child made last time can be reused.
+  fn make_child(id: ViewId, context: &mut Context, constraint: Option<Box2D>) -> Option<Rc<RefCell<View>>> {
+    let mount = context.get_engine().state_map.get_mut(&id).unwrap();
+
+    let constraint = match constraint {
+      Some(constraint) if mount.is_dirty || constraint != mount.constraint => constraint,
+      None if mount.is_dirty => mount.constraint,
+      _ => return mount.child.clone(),
+    };
+
+    let child = mount.state.borrow().make(constraint);
+
+    mount.child = child.map(Sharable::into_shared);
+
+    mount.constraint = constraint;
+    mount.is_dirty = false;
+    mount.child.clone()
+  }
+
+  fn measure(view: &mut View, slot: Slot, context: &mut Context, constraints: Constraints) -> (f32, f32) {
+    let id = Engine::get_view_id(view, slot);
+
+    if let Some(&(measured_constraints, size)) = context.get_engine().size_map.get(&id) {
+      if measured_constraints == constraints {
+        return size;
+      }
+    }
+
+    let size = match view {
+      View::StatelessLayout(layout) => {
+        let mut children = layout.make(constraints.get_box()).into_iter().collect::<Vec<_>>();
+        layout.measure(constraints, &mut Children::new(id, &mut children, context))
+      },
+      View::StatefulLayout(layout) => {
+        let state = Engine::get_state(&mut **layout, id, context);
+
+        let mut children = Engine::make_child(id, context, None)
+          .map(Sharable::Shared)
+          .into_iter()
+          .collect::<Vec<_>>();
+
+        let size = state
           .borrow()
-          .post_draw(canvas, constraint);
+          .measure(constraints, &mut Children::new(id, &mut children, context));
+
+        size
       },
-      None => {},
-    }
+      View::MultiChildLayout(layout) => {
+        let mut children = layout.make(constraints.get_box());
+        layout.measure(constraints, &mut Children::new(id, &mut children, context))
+      },
+      View::Node(node) => node.measure(constraints),
+    };
+
+    context.get_engine().size_map.insert(id, (constraints, size));
+    size
   }
 
-  fn draw_multi_child_layout(&mut self, layout: &mut dyn MultiChildLayout, canvas: &Canvas, constraint: Box2D) {
-    layout.pre_draw(canvas, constraint);
+  fn arrange(view: &mut View, slot: Slot, context: &mut Context, rect: Box2D) {
+    let id = Engine::get_view_id(view, slot);
+    let engine = context.get_engine();
+    engine.rect_map.insert(id, rect);
+    engine.arrange_path.push(id);
+
+    match view {
+      View::StatelessLayout(layout) => {
+        let mut children = layout.make(rect).into_iter().collect::<Vec<_>>();
+        let child_rect = layout.arrange(rect, &mut Children::new(id, &mut children, context));
+
+        if let Some(child) = children.first_mut() {
+          Engine::visit(child, |child| {
+            Engine::arrange(child, Slot::new(id, 0), context, child_rect)
+          });
+        }
+      },
+      View::StatefulLayout(layout) => {
+        let state = Engine::get_state(&mut **layout, id, context);
+        let is_focus_scope = state.borrow().is_focus_scope();
+        let engine = context.get_engine();
 
-    let mut child_constraint = constraint;
+        if state.borrow().is_focusable() {
+          engine.focus_chain.push(Focusable {
+            id,
+            scope: engine.scope_path.last().copied().unwrap_or(ViewId::ROOT),
+            path: engine.arrange_path.clone(),
+          });
+        }
 
-    for child in layout.make(constraint) {
-      match child {
-        Sharable::Owned(mut child) => {
-          self.draw_view(&mut child, canvas, child_constraint);
+        if is_focus_scope {
+          engine.scope_path.push(id);
+        }
 
-          // Tell the layout to reduce the constraint for the next child
-          child_constraint = layout.calc_rect_left(child_constraint, &child);
-        },
-        Sharable::Shared(child) => {
-          let mut child = child.borrow_mut();
-          self.draw_view(&mut child, canvas, child_constraint);
+        let mut children = Engine::make_child(id, context, Some(rect))
+          .map(Sharable::Shared)
+          .into_iter()
+          .collect::<Vec<_>>();
 
-          // Tell the layout to reduce the constraint for the next child
-          child_constraint = layout.calc_rect_left(child_constraint, &child);
-        },
-      }
+        let child_rect = state
+          .borrow()
+          .arrange(rect, &mut Children::new(id, &mut children, context));
+
+        if let Some(child) = children.first_mut() {
+          Engine::visit(child, |child| {
+            Engine::arrange(child, Slot::new(id, 0), context, child_rect)
+          });
+        }
+
+        if is_focus_scope {
+          context.get_engine().scope_path.pop();
+        }
+      },
+      View::MultiChildLayout(layout) => {
+        let mut children = layout.make(rect);
+        let child_rects = layout.arrange(rect, &mut Children::new(id, &mut children, context));
+
+        for (index, (child, child_rect)) in children.iter_mut().zip(child_rects).enumerate() {
+          Engine::visit(child, |child| {
+            Engine::arrange(child, Slot::new(id, index), context, child_rect)
+          });
+        }
+      },
+      View::Node(_) => {},
     }
 
-    layout.post_draw(canvas, constraint);
+    context.get_engine().arrange_path.pop();
   }
 
-  pub(super) fn on_event(view: &mut View, context: &mut Context, event: &Event) {
+  fn draw_view(view: &mut View, slot: Slot, context: &mut Context, canvas: &Canvas, parent_rect: Box2D) {
+    let id = Engine::get_view_id(view, slot);
+    let rect = Engine::get_rect(id, context, parent_rect);
+
     match view {
-      View::StatelessLayout(layout) => Engine::on_event_in_stateless_layout(&mut **layout, context, event),
-      View::StatefulLayout(layout) => Engine::on_event_in_stateful_layout(&mut **layout, context, event),
-      View::MultiChildLayout(layout) => Engine::on_event_in_multi_child_layout(&mut **layout, context, event),
-      View::Node(node) => node.on_event(context, event),
+      View::StatelessLayout(layout) => Engine::draw_stateless_layout(&mut **layout, id, context, canvas, rect),
+      View::StatefulLayout(layout) => Engine::draw_stateful_layout(&mut **layout, id, context, canvas, rect),
+      View::MultiChildLayout(layout) => Engine::draw_multi_child_layout(&mut **layout, id, context, canvas, rect),
+      View::Node(node) => node.draw(canvas, rect),
+    }
+  }
+
+  fn draw_stateless_layout(
+    layout: &mut dyn StatelessLayout,
+    id: ViewId,
+    context: &mut Context,
+    canvas: &Canvas,
+    rect: Box2D,
+  ) {
+    if let Some(mut child) = layout.make(rect) {
+      layout.pre_draw(canvas, rect);
+
+      Engine::with_transform(canvas, layout.get_transform(rect), || {
+        Engine::visit(&mut child, |child| {
+          Engine::draw_view(child, Slot::new(id, 0), context, canvas, rect)
+        });
+      });
+
+      layout.post_draw(canvas, rect);
+    }
+  }
+
+  fn draw_stateful_layout(
+    layout: &mut dyn StatefulLayout,
+    id: ViewId,
+    context: &mut Context,
+    canvas: &Canvas,
+    rect: Box2D,
+  ) {
+    let state = Engine::get_state(layout, id, context);
+
+    if let Some(child) = Engine::make_child(id, context, Some(rect)) {
+      state.borrow().pre_draw(canvas, rect);
+
+      let transform = state.borrow().get_transform(rect);
+
+      Engine::with_transform(canvas, transform, || {
+        Engine::draw_view(&mut child.borrow_mut(), Slot::new(id, 0), context, canvas, rect)
+      });
+
+      state.borrow().post_draw(canvas, rect);
     }
   }
 
-  fn on_event_in_stateless_layout(layout: &mut dyn StatelessLayout, context: &mut Context, event: &Event) {
-    match layout.make(NO_CONSTRAINT) {
-      Some(Sharable::Owned(mut child)) => Engine::on_event(&mut child, context, event),
-      Some(Sharable::Shared(child)) => Engine::on_event(&mut child.borrow_mut(), context, event),
-      None => {},
+  fn draw_multi_child_layout(
+    layout: &mut dyn MultiChildLayout,
+    id: ViewId,
+    context: &mut Context,
+    canvas: &Canvas,
+    rect: Box2D,
+  ) {
+    layout.pre_draw(canvas, rect);
+
+    for (index, mut child) in Engine::make_in_z_order(layout, rect) {
+      Engine::visit(&mut child, |child| {
+        Engine::draw_view(child, Slot::new(id, index), context, canvas, rect)
+      });
     }
 
-    layout.on_event(context, event);
+    layout.post_draw(canvas, rect);
   }
 
-  fn on_event_in_stateful_layout(layout: &mut dyn StatefulLayout, context: &mut Context, event: &Event) {
-    let state = context
-      .get_engine()
-      .state_map
-      .entry(layout.get_key().to_owned())
-      .or_insert_with(|| layout.make_state())
-      .borrow();
-
-    match state.make(NO_CONSTRAINT) {
-      Some(Sharable::Owned(mut child)) => {
-        drop(state);
-        Engine::on_event(&mut child, context, event);
+  // Makes the children of the given layout together with their indices, ordered from the bottom one to the top one
+  fn make_in_z_order(layout: &dyn MultiChildLayout, rect: Box2D) -> Vec<(usize, Sharable<View>)> {
+    let z_indices = layout.get_z_indices();
+    let mut children = layout.make(rect).into_iter().enumerate().collect::<Vec<_>>();
+    children.sort_by_key(|(index, _)| z_indices.get(*index).copied().unwrap_or(0));
+    children
+  }
+
+  fn with_transform(canvas: &Canvas, transform: Option<Matrix>, draw: impl FnOnce()) {
+    let Some(transform) = transform else {
+      draw();
+      return;
+    };
+
+    canvas.save();
+    canvas.concat(&transform);
+    draw();
+    canvas.restore();
+  }
+
+  // Maps pointer positions in the given event and the given point from the parent space into the child space of the
+  // given transform, so that the child receives positions in the same space it is drawn in
+  fn untransform_event<'a>(
+    event: &'a Event,
+    point: Option<(f32, f32)>,
+    transform: Option<Matrix>,
+    root_rect: Box2D,
+  ) -> (Cow<'a, Event>, Option<(f32, f32)>) {
+    let Some(inverse) = transform.and_then(|transform| transform.invert()) else {
+      return (Cow::Borrowed(event), point);
+    };
+
+    let map_point = |point: (f32, f32)| {
+      let point = inverse.map_point(point);
+      (point.x, point.y)
+    };
+
+    let map_vector = |vector: (f32, f32)| {
+      let vector = inverse.map_vector(vector);
+      (vector.x, vector.y)
+    };
+
+    // Touch positions are normalized to the root rect
+    let map_touch = |x: f32, y: f32, dx: f32, dy: f32| {
+      let (x, y) = map_point((
+        root_rect.position.0 + x * root_rect.size.0,
+        root_rect.position.1 + y * root_rect.size.1,
+      ));
+      let (dx, dy) = map_vector((dx * root_rect.size.0, dy * root_rect.size.1));
+
+      (
+        (x - root_rect.position.0) / root_rect.size.0,
+        (y - root_rect.position.1) / root_rect.size.1,
+        dx / root_rect.size.0,
+        dy / root_rect.size.1,
+      )
+    };
+
+    let mut event = event.clone();
+
+    match &mut event {
+      Event::MouseMotion { x, y, xrel, yrel, .. } => {
+        let (mapped_x, mapped_y) = map_point((*x as _, *y as _));
+        let (mapped_xrel, mapped_yrel) = map_vector((*xrel as _, *yrel as _));
+        (*x, *y) = (mapped_x.round() as _, mapped_y.round() as _);
+        (*xrel, *yrel) = (mapped_xrel.round() as _, mapped_yrel.round() as _);
       },
-      Some(Sharable::Shared(child)) => {
-        drop(state);
-        Engine::on_event(&mut child.borrow_mut(), context, event);
+      Event::MouseButtonDown { x, y, .. } | Event::MouseButtonUp { x, y, .. } => {
+        let (mapped_x, mapped_y) = map_point((*x as _, *y as _));
+        (*x, *y) = (mapped_x.round() as _, mapped_y.round() as _);
       },
-      None => drop(state),
+      Event::FingerDown { x, y, dx, dy, .. }
+      | Event::FingerUp { x, y, dx, dy, .. }
+      | Event::FingerMotion { x, y, dx, dy, .. } => (*x, *y, *dx, *dy) = map_touch(*x, *y, *dx, *dy),
+      _ => {},
     }
 
-    let state = Rc::clone(
-      context
-        .get_engine()
-        .state_map
-        .get(&layout.get_key().to_owned())
-        .unwrap(),
-    );
+    (Cow::Owned(event), point.map(map_point))
+  }
+
+  // Where the given pointer event happens in the root space, or None if the given event is not a pointer event
+  fn get_pointer_position(&self, event: &Event) -> Option<(f32, f32)> {
+    match *event {
+      Event::MouseMotion { x, y, .. } | Event::MouseButtonDown { x, y, .. } | Event::MouseButtonUp { x, y, .. } => {
+        Some((x as _, y as _))
+      },
+
+      // Mouse wheel events do not tell where the mouse is
+      Event::MouseWheel { .. } => self.mouse_position,
+
+      Event::FingerDown { x, y, .. } | Event::FingerUp { x, y, .. } | Event::FingerMotion { x, y, .. } => Some((
+        self.root_rect.position.0 + x * self.root_rect.size.0,
+        self.root_rect.position.1 + y * self.root_rect.size.1,
+      )),
+      _ => None,
+    }
+  }
+
+  pub(super) fn stop_propagation(&mut self) {
+    self.is_propagation_stopped = true;
+  }
+
+  pub(super) const fn get_current_rect(&self) -> Option<Box2D> {
+    self.current_rect
+  }
+
+  pub(super) const fn get_current_pointer_position(&self) -> Option<(f32, f32)> {
+    self.current_pointer_position
+  }
+
+  // Lets the callbacks of a view get the given rect and pointer position of the view from the context
+  fn with_scope<R>(
+    context: &mut Context,
+    rect: Box2D,
+    point: Option<(f32, f32)>,
+    callback: impl FnOnce(&mut Context) -> R,
+  ) -> R {
+    let engine = context.get_engine();
+    let prev_rect = engine.current_rect.replace(rect);
+    let prev_point = mem::replace(&mut engine.current_pointer_position, point);
+
+    let result = callback(context);
 
-    // Drop the mutable borrow of engine from the given context here, because later on_event(context, event) call might
-    // might mutably borrow this engine
+    let engine = context.get_engine();
+    engine.current_rect = prev_rect;
+    engine.current_pointer_position = prev_point;
+    result
+  }
+
+  fn is_propagation_stopped(context: &mut Context) -> bool {
+    context.get_engine().is_propagation_stopped
+  }
 
-    state.borrow_mut().on_event(context, event);
+  // Whether the key event being dispatched has gone down the given view on the focus path already
+  fn is_reached(context: &mut Context, id: ViewId) -> bool {
+    let engine = context.get_engine();
+    engine.reached_path.as_ref().is_some_and(|path| path.contains(&id))
   }
 
-  fn on_event_in_multi_child_layout(layout: &mut dyn MultiChildLayout, context: &mut Context, event: &Event) {
-    for child in layout.make(NO_CONSTRAINT) {
-      match child {
-        Sharable::Owned(mut child) => Engine::on_event(&mut child, context, event),
-        Sharable::Shared(child) => Engine::on_event(&mut child.borrow_mut(), context, event),
+  // Returns whether the given view is under the pointer of the given event. Pointer events with the given point in the
+  // parent space are only dispatched to views under the point and views captured by pressing them, where views covered
+  // by a sibling are not under the point. Key events go down the focus path first while any view has focus.
+  fn on_event(
+    view: &mut View,
+    slot: Slot,
+    context: &mut Context,
+    event: &Event,
+    point: Option<(f32, f32)>,
+    is_covered: bool,
+  ) -> bool {
+    let id = Engine::get_view_id(view, slot);
+    let engine = context.get_engine();
+
+    if let Some(focus_path) = &engine.focus_path {
+      if !focus_path.contains(&id) {
+        return false;
       }
     }
 
-    layout.on_event(context, event);
+    // The focused view has already passed the key event on to its descendants
+    if engine.reached_path.as_ref().and_then(|path| path.last()) == Some(&id) {
+      return false;
+    }
+
+    let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+
+    // Transformed views are hit where they are drawn, unless they are clipped to their rect
+    let local_point = match Engine::get_hit_transform(view, id, context, rect) {
+      Some(transform) => point.map(|point| {
+        let point = transform
+          .invert()
+          .map_or(point.into(), |inverse| inverse.map_point(point));
+        (point.x, point.y)
+      }),
+      None => point,
+    };
+
+    Engine::with_scope(context, rect, point, |context| {
+      let Some(point) = point else {
+        // Descendants of the focused view are off the focus path
+        let engine = context.get_engine();
+        let is_focused = engine.focus_path.as_ref().and_then(|path| path.last()) == Some(&id);
+        let focus_path = if is_focused { engine.focus_path.take() } else { None };
+
+        Engine::dispatch_event(view, id, context, event, None);
+
+        if focus_path.is_some() {
+          context.get_engine().focus_path = focus_path;
+        }
+
+        return false;
+      };
+
+      let engine = context.get_engine();
+      let is_under_pointer = !is_covered && local_point.is_some_and(|local_point| rect.contains(local_point));
+      let is_hovered = engine.hovered_ids.contains(&id);
+      let is_captured = engine.captured_ids.contains(&id);
+
+      if is_under_pointer {
+        engine.hit_ids.insert(id);
+      }
+
+      // Only mouse motion moves the pointer onto, over or off views
+      if let Event::MouseMotion { .. } = event {
+        if is_under_pointer {
+          if !is_hovered {
+            Engine::on_pointer_event(view, id, context, &PointerEvent::Enter);
+          }
+
+          Engine::on_pointer_event(view, id, context, &PointerEvent::Hover { position: point });
+        } else if is_hovered {
+          Engine::leave(view, id, context);
+        }
+      }
+
+      if is_under_pointer {
+        Engine::dispatch_event(view, id, context, event, Some(point));
+      } else if is_captured {
+        Engine::dispatch_event(view, id, context, event, Some(point));
+
+        // Only views under the pointer can keep the event from their siblings
+        context.get_engine().is_propagation_stopped = false;
+      }
+
+      is_under_pointer
+    })
   }
 
-  pub(super) fn tick(view: &mut View, context: &mut Context, dt: f32) {
+  fn dispatch_event(view: &mut View, id: ViewId, context: &mut Context, event: &Event, point: Option<(f32, f32)>) {
     match view {
-      View::StatelessLayout(layout) => Engine::tick_in_stateless_layout(&mut **layout, context, dt),
-      View::StatefulLayout(layout) => Engine::tick_in_stateful_layout(&mut **layout, context, dt),
-      View::MultiChildLayout(layout) => Engine::tick_in_multi_child_layout(&mut **layout, context, dt),
-      View::Node(node) => node.tick(context, dt),
+      View::StatelessLayout(layout) => Engine::on_event_in_stateless_layout(&mut **layout, id, context, event, point),
+      View::StatefulLayout(layout) => Engine::on_event_in_stateful_layout(&mut **layout, id, context, event, point),
+      View::MultiChildLayout(layout) => {
+        Engine::on_event_in_multi_child_layout(&mut **layout, id, context, event, point)
+      },
+      View::Node(node) => node.on_event(context, event),
     }
   }
 
-  fn tick_in_stateless_layout(layout: &mut dyn StatelessLayout, context: &mut Context, dt: f32) {
-    match layout.make(NO_CONSTRAINT) {
-      Some(Sharable::Owned(mut child)) => Engine::tick(&mut child, context, dt),
-      Some(Sharable::Shared(child)) => Engine::tick(&mut child.borrow_mut(), context, dt),
-      None => {},
-    }
+  // The transform which the given view is drawn with in the given rect of it, or None if it is hit tested against its
+  // rect as is
+  fn get_hit_transform(view: &mut View, id: ViewId, context: &mut Context, rect: Box2D) -> Option<Matrix> {
+    match view {
+      View::StatelessLayout(layout) if !layout.is_clipping() => layout.get_transform(rect),
+      View::StatefulLayout(layout) => {
+        let state = Engine::get_state(&mut **layout, id, context);
+        let state = state.borrow();
 
-    layout.tick(context, dt);
+        if state.is_clipping() {
+          None
+        } else {
+          state.get_transform(rect)
+        }
+      },
+      _ => None,
+    }
   }
 
-  fn tick_in_stateful_layout(layout: &mut dyn StatefulLayout, context: &mut Context, dt: f32) {
-    let state = context
-      .get_engine()
-      .state_map
-      .entry(layout.get_key().to_owned())
-      .or_insert_with(|| layout.make_state())
-      .borrow();
-
-    match state.make(NO_CONSTRAINT) {
-      Some(Sharable::Owned(mut child)) => {
-        drop(state);
-        Engine::tick(&mut child, context, dt);
+  fn on_pointer_event(view: &mut View, id: ViewId, context: &mut Context, event: &PointerEvent) {
+    match view {
+      View::StatelessLayout(layout) => layout.on_pointer_event(context, event),
+      View::StatefulLayout(layout) => {
+        let state = Engine::get_state(&mut **layout, id, context);
+        Engine::call_back(id, context, |context| {
+          state.borrow_mut().on_pointer_event(context, event)
+        });
       },
-      Some(Sharable::Shared(child)) => {
-        drop(state);
-        Engine::tick(&mut child.borrow_mut(), context, dt);
+      View::MultiChildLayout(layout) => layout.on_pointer_event(context, event),
+      View::Node(node) => node.on_pointer_event(context, event),
+    }
+  }
+
+  // Sends Leave to the given view and its descendants which the pointer has moved off
+  fn leave(view: &mut View, id: ViewId, context: &mut Context) {
+    Engine::on_pointer_event(view, id, context, &PointerEvent::Leave);
+
+    let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+
+    let mut children = match view {
+      View::StatelessLayout(layout) => layout.make(rect).into_iter().collect(),
+      View::StatefulLayout(layout) => {
+        Engine::get_state(&mut **layout, id, context);
+
+        Engine::make_child(id, context, None)
+          .map(Sharable::Shared)
+          .into_iter()
+          .collect()
       },
-      None => drop(state),
+      View::MultiChildLayout(layout) => layout.make(rect),
+      View::Node(_) => vec![],
+    };
+
+    for (index, child) in children.iter_mut().enumerate() {
+      Engine::visit(child, |child| {
+        let child_id = Engine::get_view_id(child, Slot::new(id, index));
+        let child_rect = Engine::get_rect(child_id, context, rect);
+
+        if context.get_engine().hovered_ids.contains(&child_id) {
+          Engine::with_scope(context, child_rect, None, |context| {
+            Engine::leave(child, child_id, context)
+          });
+        }
+      });
     }
+  }
 
-    let state = Rc::clone(
-      context
-        .get_engine()
-        .state_map
-        .get(&layout.get_key().to_owned())
-        .unwrap(),
-    );
+  fn on_event_in_stateless_layout(
+    layout: &mut dyn StatelessLayout,
+    id: ViewId,
+    context: &mut Context,
+    event: &Event,
+    point: Option<(f32, f32)>,
+  ) {
+    let is_reached = Engine::is_reached(context, id);
+
+    if !is_reached {
+      layout.on_capture_event(context, event);
+    }
 
-    // Drop the mutable borrow of engine from the given context here, because later on_event(context, event) call might
-    // might mutably borrow this engine
+    if Engine::is_propagation_stopped(context) {
+      return;
+    }
+
+    let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+
+    if let Some(mut child) = layout.make(rect) {
+      let root_rect = context.get_engine().root_rect;
+      let (child_event, child_point) = Engine::untransform_event(event, point, layout.get_transform(rect), root_rect);
 
-    state.borrow_mut().tick(context, dt);
+      Engine::visit(&mut child, |child| {
+        Engine::on_event(child, Slot::new(id, 0), context, &child_event, child_point, false)
+      });
+
+      if Engine::is_propagation_stopped(context) {
+        return;
+      }
+    }
+
+    if !is_reached {
+      layout.on_event(context, event);
+    }
   }
 
-  fn tick_in_multi_child_layout(layout: &mut dyn MultiChildLayout, context: &mut Context, dt: f32) {
-    for child in layout.make(NO_CONSTRAINT) {
-      match child {
-        Sharable::Owned(mut child) => Engine::tick(&mut child, context, dt),
-        Sharable::Shared(child) => Engine::tick(&mut child.borrow_mut(), context, dt),
+  fn on_event_in_stateful_layout(
+    layout: &mut dyn StatefulLayout,
+    id: ViewId,
+    context: &mut Context,
+    event: &Event,
+    point: Option<(f32, f32)>,
+  ) {
+    let state = Engine::get_state(layout, id, context);
+    let is_reached = Engine::is_reached(context, id);
+
+    if !is_reached {
+      Engine::call_back(id, context, |context| {
+        state.borrow_mut().on_capture_event(context, event)
+      });
+    }
+
+    if Engine::is_propagation_stopped(context) {
+      return;
+    }
+
+    if let Some(child) = Engine::make_child(id, context, None) {
+      let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+      let root_rect = context.get_engine().root_rect;
+      let transform = state.borrow().get_transform(rect);
+      let (child_event, child_point) = Engine::untransform_event(event, point, transform, root_rect);
+
+      Engine::on_event(
+        &mut child.borrow_mut(),
+        Slot::new(id, 0),
+        context,
+        &child_event,
+        child_point,
+        false,
+      );
+
+      if Engine::is_propagation_stopped(context) {
+        return;
       }
     }
 
+    if !is_reached {
+      Engine::call_back(id, context, |context| state.borrow_mut().on_event(context, event));
+    }
+  }
+
+  fn on_event_in_multi_child_layout(
+    layout: &mut dyn MultiChildLayout,
+    id: ViewId,
+    context: &mut Context,
+    event: &Event,
+    point: Option<(f32, f32)>,
+  ) {
+    let is_reached = Engine::is_reached(context, id);
+
+    if !is_reached {
+      layout.on_capture_event(context, event);
+    }
+
+    if Engine::is_propagation_stopped(context) {
+      return;
+    }
+
+    let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+    let mut children = Engine::make_in_z_order(layout, rect);
+
+    // Pointer events only reach the topmost child under the pointer, which is the last one drawn
+    if point.is_some() {
+      children.reverse();
+    }
+
+    let mut is_hit = false;
+
+    for (index, mut child) in children {
+      Engine::visit(&mut child, |child| {
+        // Covered by a sibling once any child is hit, so the pointer is no longer over it
+        is_hit |= Engine::on_event(child, Slot::new(id, index), context, event, point, is_hit);
+      });
+
+      if Engine::is_propagation_stopped(context) {
+        return;
+      }
+    }
+
+    if !is_reached {
+      layout.on_event(context, event);
+    }
+  }
+
+  fn tick(view: &mut View, slot: Slot, context: &mut Context, dt: f32) {
+    let id = Engine::get_view_id(view, slot);
+    let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+
+    Engine::with_scope(context, rect, None, |context| match view {
+      View::StatelessLayout(layout) => Engine::tick_in_stateless_layout(&mut **layout, id, context, dt),
+      View::StatefulLayout(layout) => Engine::tick_in_stateful_layout(&mut **layout, id, context, dt),
+      View::MultiChildLayout(layout) => Engine::tick_in_multi_child_layout(&mut **layout, id, context, dt),
+      View::Node(node) => node.tick(context, dt),
+    })
+  }
+
+  fn tick_in_stateless_layout(layout: &mut dyn StatelessLayout, id: ViewId, context: &mut Context, dt: f32) {
+    if let Some(mut child) = layout.make(Engine::get_rect(id, context, NO_CONSTRAINT)) {
+      Engine::visit(&mut child, |child| Engine::tick(child, Slot::new(id, 0), context, dt));
+    }
+
+    layout.tick(context, dt);
+  }
+
+  fn tick_in_stateful_layout(layout: &mut dyn StatefulLayout, id: ViewId, context: &mut Context, dt: f32) {
+    let state = Engine::get_state(layout, id, context);
+
+    if let Some(child) = Engine::make_child(id, context, None) {
+      Engine::tick(&mut child.borrow_mut(), Slot::new(id, 0), context, dt);
+    }
+
+    Engine::call_back(id, context, |context| state.borrow_mut().tick(context, dt));
+  }
+
+  fn tick_in_multi_child_layout(layout: &mut dyn MultiChildLayout, id: ViewId, context: &mut Context, dt: f32) {
+    let rect = Engine::get_rect(id, context, NO_CONSTRAINT);
+
+    for (index, mut child) in layout.make(rect).into_iter().enumerate() {
+      Engine::visit(&mut child, |child| {
+        Engine::tick(child, Slot::new(id, index), context, dt)
+      });
+    }
+
     layout.tick(context, dt);
   }
 }
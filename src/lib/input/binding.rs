@@ -0,0 +1,122 @@
+use sdl2::{
+  controller::{Axis, Button},
+  event::Event,
+  keyboard::Keycode,
+  mouse::MouseButton,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+  fmt::{self, Display, Formatter},
+  str::FromStr,
+};
+
+// A physical input which triggers an action. Saved in config files as "key:<SDL key name>", "mouse:<button>",
+// "button:<SDL game controller button name>" or "axis:<+ or -><SDL game controller axis name>", e.g. "key:Left Shift",
+// "mouse:Right", "button:dpup" and "axis:-lefty".
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Binding {
+  Key(Keycode),
+  MouseButton(MouseButton),
+
+  // Of any game controller
+  ControllerButton(Button),
+
+  // Of any game controller, which is pressed while pushed beyond the dead zone towards the given sign
+  ControllerAxis { axis: Axis, is_positive: bool },
+}
+
+impl Binding {
+  // The binding pressed or released by the given event together with whether it is pressed, or None if the given event
+  // does not press or release anything
+  pub fn from_event(event: &Event) -> Option<(Self, bool)> {
+    match *event {
+      Event::KeyDown {
+        keycode: Some(keycode), ..
+      } => Some((Self::Key(keycode), true)),
+      Event::KeyUp {
+        keycode: Some(keycode), ..
+      } => Some((Self::Key(keycode), false)),
+      Event::MouseButtonDown { mouse_btn, .. } => Some((Self::MouseButton(mouse_btn), true)),
+      Event::MouseButtonUp { mouse_btn, .. } => Some((Self::MouseButton(mouse_btn), false)),
+      Event::ControllerButtonDown { button, .. } => Some((Self::ControllerButton(button), true)),
+      Event::ControllerButtonUp { button, .. } => Some((Self::ControllerButton(button), false)),
+      _ => None,
+    }
+  }
+}
+
+impl Display for Binding {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Key(keycode) => write!(fmt, "key:{}", keycode.name()),
+      Self::MouseButton(mouse_button) => {
+        let name = match mouse_button {
+          MouseButton::Left => "Left",
+          MouseButton::Middle => "Middle",
+          MouseButton::Right => "Right",
+          MouseButton::X1 => "X1",
+          MouseButton::X2 => "X2",
+          MouseButton::Unknown => "Unknown",
+        };
+
+        write!(fmt, "mouse:{name}")
+      },
+      Self::ControllerButton(button) => write!(fmt, "button:{}", button.string()),
+      Self::ControllerAxis { axis, is_positive } => {
+        write!(fmt, "axis:{}{}", if *is_positive { '+' } else { '-' }, axis.string())
+      },
+    }
+  }
+}
+
+impl FromStr for Binding {
+  type Err = String;
+
+  fn from_str(binding: &str) -> Result<Self, Self::Err> {
+    let Some((kind, name)) = binding.split_once(':') else {
+      return Err(format!("binding {binding} must be in the form of <kind>:<name>"));
+    };
+
+    let parsed = match kind {
+      "key" => Keycode::from_name(name).map(Self::Key),
+      "mouse" => match name {
+        "Left" => Some(Self::MouseButton(MouseButton::Left)),
+        "Middle" => Some(Self::MouseButton(MouseButton::Middle)),
+        "Right" => Some(Self::MouseButton(MouseButton::Right)),
+        "X1" => Some(Self::MouseButton(MouseButton::X1)),
+        "X2" => Some(Self::MouseButton(MouseButton::X2)),
+        _ => None,
+      },
+      "button" => Button::from_string(name).map(Self::ControllerButton),
+      "axis" => match name.split_at(name.len().min(1)) {
+        ("+", axis) => Axis::from_string(axis).map(|axis| Self::ControllerAxis {
+          axis,
+          is_positive: true,
+        }),
+        ("-", axis) => Axis::from_string(axis).map(|axis| Self::ControllerAxis {
+          axis,
+          is_positive: false,
+        }),
+        _ => None,
+      },
+      _ => return Err(format!("kind of binding {binding} must be key, mouse, button or axis")),
+    };
+
+    parsed.ok_or_else(|| format!("{name} of binding {binding} is unknown"))
+  }
+}
+
+impl TryFrom<String> for Binding {
+  type Error = String;
+
+  fn try_from(binding: String) -> Result<Self, Self::Error> {
+    binding.parse()
+  }
+}
+
+impl From<Binding> for String {
+  fn from(binding: Binding) -> Self {
+    binding.to_string()
+  }
+}
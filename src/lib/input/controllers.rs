@@ -0,0 +1,204 @@
+use sdl2::{
+  controller::{Axis, Button, GameController},
+  event::Event,
+  GameControllerSubsystem,
+};
+use std::{
+  collections::{BTreeMap, HashMap, HashSet},
+  fmt::{self, Debug, Formatter},
+};
+
+// Sticks rarely rest exactly at the center, so axis values within this fraction of their range count as zero
+pub const DEFAULT_DEAD_ZONE: f32 = 0.25;
+
+// Maps the given raw axis value to -1..=1, where values within the given dead zone are 0 and the rest of the range is
+// rescaled so that values grow smoothly from the edge of the dead zone
+pub fn apply_dead_zone(value: i16, dead_zone: f32) -> f32 {
+  let value = (value as f32 / i16::MAX as f32).clamp(-1f32, 1f32);
+
+  if value.abs() <= dead_zone {
+    0f32
+  } else {
+    value.signum() * (value.abs() - dead_zone) / (1f32 - dead_zone)
+  }
+}
+
+// The state of a connected game controller, which is updated by its events before they are dispatched to the view tree
+pub struct Controller {
+  name: String,
+  buttons: HashSet<Button>,
+  axes: HashMap<Axis, f32>,
+
+  // SDL only reports the events of opened controllers, which are closed when dropped
+  _handle: Option<GameController>,
+}
+
+impl Debug for Controller {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Controller")
+      .field("name", &self.name)
+      .field("buttons", &self.buttons)
+      .field("axes", &self.axes)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Controller {
+  fn new(name: String, handle: Option<GameController>) -> Self {
+    Self {
+      name,
+      buttons: HashSet::new(),
+      axes: HashMap::new(),
+      _handle: handle,
+    }
+  }
+
+  pub fn get_name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn is_button_pressed(&self, button: Button) -> bool {
+    self.buttons.contains(&button)
+  }
+
+  // Within -1..=1 with the dead zone applied. Triggers are within 0..=1.
+  pub fn get_axis(&self, axis: Axis) -> f32 {
+    self.axes.get(&axis).copied().unwrap_or_default()
+  }
+}
+
+// The connected game controllers by their instance IDs, which are the `which` of controller events. Controllers are
+// opened when plugged in and closed when unplugged, including those plugged in before the app starts.
+pub struct Controllers {
+  subsystem: Option<GameControllerSubsystem>,
+  controllers: BTreeMap<u32, Controller>,
+  dead_zone: f32,
+}
+
+impl Default for Controllers {
+  fn default() -> Self {
+    Self {
+      subsystem: None,
+      controllers: BTreeMap::new(),
+      dead_zone: DEFAULT_DEAD_ZONE,
+    }
+  }
+}
+
+impl Debug for Controllers {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Controllers")
+      .field("controllers", &self.controllers)
+      .field("dead_zone", &self.dead_zone)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Controllers {
+  pub(crate) fn init(&mut self, subsystem: GameControllerSubsystem) {
+    self.subsystem = Some(subsystem);
+  }
+
+  pub(crate) fn on_event(&mut self, event: &Event) {
+    match *event {
+      // The `which` of this event is a device index rather than an instance ID
+      Event::ControllerDeviceAdded { which, .. } => {
+        let Some(subsystem) = &self.subsystem else {
+          return;
+        };
+
+        // A controller can fail to open, e.g. when it is unplugged right away, which should not bring down the app
+        match subsystem.open(which) {
+          Ok(handle) => {
+            self
+              .controllers
+              .insert(handle.instance_id(), Controller::new(handle.name(), Some(handle)));
+          },
+          Err(err) => eprintln!("Failed to open the game controller {which}: {err}"),
+        }
+      },
+      Event::ControllerDeviceRemoved { which, .. } => {
+        self.controllers.remove(&which);
+      },
+
+      // Also track controllers which are never opened, e.g. by events pushed to a simulator
+      Event::ControllerButtonDown { which, button, .. } => {
+        self.get_or_insert(which).buttons.insert(button);
+      },
+      Event::ControllerButtonUp { which, button, .. } => {
+        self.get_or_insert(which).buttons.remove(&button);
+      },
+      Event::ControllerAxisMotion { which, axis, value, .. } => {
+        let value = apply_dead_zone(value, self.dead_zone);
+        self.get_or_insert(which).axes.insert(axis, value);
+      },
+      _ => {},
+    }
+  }
+
+  fn get_or_insert(&mut self, id: u32) -> &mut Controller {
+    self
+      .controllers
+      .entry(id)
+      .or_insert_with(|| Controller::new(String::new(), None))
+  }
+
+  pub fn get(&self, id: u32) -> Option<&Controller> {
+    self.controllers.get(&id)
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (u32, &Controller)> {
+    self.controllers.iter().map(|(&id, controller)| (id, controller))
+  }
+
+  pub fn len(&self) -> usize {
+    self.controllers.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.controllers.is_empty()
+  }
+
+  // Whether the given button is held down on any controller
+  pub fn is_button_pressed(&self, button: Button) -> bool {
+    self
+      .controllers
+      .values()
+      .any(|controller| controller.is_button_pressed(button))
+  }
+
+  // The given axis of the controller pushing it the furthest, so that any controller can steer
+  pub fn get_axis(&self, axis: Axis) -> f32 {
+    self
+      .controllers
+      .values()
+      .map(|controller| controller.get_axis(axis))
+      .fold(
+        0f32,
+        |furthest, value| {
+          if value.abs() > furthest.abs() {
+            value
+          } else {
+            furthest
+          }
+        },
+      )
+  }
+
+  pub const fn get_dead_zone(&self) -> f32 {
+    self.dead_zone
+  }
+
+  // Takes effect from the next axis motion
+  pub fn set_dead_zone(&mut self, dead_zone: f32) {
+    // Preconditions
+    assert!(
+      (0f32..1f32).contains(&dead_zone),
+      "dead_zone must be at least 0 and less than 1"
+    );
+
+    self.dead_zone = dead_zone;
+  }
+}
@@ -0,0 +1,82 @@
+use super::Binding;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+// Binds named actions such as "turn_up" and "pause" to physical inputs, so that games query actions instead of keys
+// and players can rebind them. Saved in config files as JSON, e.g.
+// {
+//   "pause": ["key:P", "button:start"],
+//   "turn_up": ["key:W", "key:Up", "button:dpup"]
+// }
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InputMap {
+  // Sorted by action so that saved config files are stable
+  actions: BTreeMap<String, Vec<Binding>>,
+}
+
+impl InputMap {
+  pub fn with_action(mut self, action: &str, bindings: impl IntoIterator<Item = Binding>) -> Self {
+    self.set_bindings(action, bindings);
+    self
+  }
+
+  // Replaces all the bindings of the given action
+  pub fn set_bindings(&mut self, action: &str, bindings: impl IntoIterator<Item = Binding>) {
+    let mut unique_bindings = Vec::new();
+
+    for binding in bindings {
+      if !unique_bindings.contains(&binding) {
+        unique_bindings.push(binding);
+      }
+    }
+
+    self.actions.insert(action.to_owned(), unique_bindings);
+  }
+
+  pub fn bind(&mut self, action: &str, binding: Binding) {
+    let bindings = self.actions.entry(action.to_owned()).or_default();
+
+    if !bindings.contains(&binding) {
+      bindings.push(binding);
+    }
+  }
+
+  pub fn unbind(&mut self, action: &str, binding: Binding) {
+    if let Some(bindings) = self.actions.get_mut(action) {
+      bindings.retain(|&bound| bound != binding);
+    }
+  }
+
+  pub fn get_bindings(&self, action: &str) -> &[Binding] {
+    self.actions.get(action).map_or(&[], |bindings| bindings)
+  }
+
+  pub fn is_bound(&self, action: &str, binding: Binding) -> bool {
+    self.get_bindings(action).contains(&binding)
+  }
+
+  pub fn get_actions(&self) -> impl Iterator<Item = &str> {
+    self.actions.keys().map(|action| action.as_str())
+  }
+
+  pub fn from_json(json: &str) -> serde_json::Result<Self> {
+    serde_json::from_str(json)
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string_pretty(self).unwrap()
+  }
+
+  // Replaces the bindings of the actions in the given config file, while other actions keep their bindings. This lets
+  // games declare the default bindings before loading the bindings players have changed.
+  pub fn load(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+    let input_map = InputMap::from_json(&fs::read_to_string(path)?)?;
+    self.actions.extend(input_map.actions);
+    Ok(())
+  }
+
+  pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(path, self.to_json())
+  }
+}
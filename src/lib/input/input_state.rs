@@ -0,0 +1,90 @@
+use super::{controllers, Binding};
+use sdl2::event::Event;
+use std::collections::{HashMap, HashSet};
+
+// Which bindings are held down, together with the bindings pressed and released since the end of the previous tick
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputState {
+  pressed: HashSet<Binding>,
+  just_pressed: HashSet<Binding>,
+  just_released: HashSet<Binding>,
+
+  // The controller bindings held down by each controller instance ID. A controller binding is pressed while any
+  // controller holds it down.
+  controller_pressed: HashMap<u32, HashSet<Binding>>,
+}
+
+impl InputState {
+  pub(crate) fn on_event(&mut self, event: &Event, dead_zone: f32) {
+    match *event {
+      Event::ControllerButtonDown { which, button, .. } => {
+        self.set_controller_pressed(which, Binding::ControllerButton(button), true);
+      },
+      Event::ControllerButtonUp { which, button, .. } => {
+        self.set_controller_pressed(which, Binding::ControllerButton(button), false);
+      },
+      Event::ControllerAxisMotion { which, axis, value, .. } => {
+        let value = controllers::apply_dead_zone(value, dead_zone);
+
+        for is_positive in [false, true] {
+          let is_pressed = if is_positive { value > 0f32 } else { value < 0f32 };
+          self.set_controller_pressed(which, Binding::ControllerAxis { axis, is_positive }, is_pressed);
+        }
+      },
+
+      // Release what the unplugged controller was holding down, since its release events never come
+      Event::ControllerDeviceRemoved { which, .. } => {
+        for binding in self.controller_pressed.remove(&which).unwrap_or_default() {
+          self.set_controller_pressed(which, binding, false);
+        }
+      },
+      _ => {
+        if let Some((binding, is_pressed)) = Binding::from_event(event) {
+          self.set_pressed(binding, is_pressed);
+        }
+      },
+    }
+  }
+
+  fn set_pressed(&mut self, binding: Binding, is_pressed: bool) {
+    // Key repeats and axis motions within the same sign are not presses
+    if is_pressed {
+      if self.pressed.insert(binding) {
+        self.just_pressed.insert(binding);
+      }
+    } else if self.pressed.remove(&binding) {
+      self.just_released.insert(binding);
+    }
+  }
+
+  fn set_controller_pressed(&mut self, which: u32, binding: Binding, is_pressed: bool) {
+    if is_pressed {
+      self.controller_pressed.entry(which).or_default().insert(binding);
+    } else if let Some(bindings) = self.controller_pressed.get_mut(&which) {
+      bindings.remove(&binding);
+    }
+
+    let is_held = self
+      .controller_pressed
+      .values()
+      .any(|bindings| bindings.contains(&binding));
+    self.set_pressed(binding, is_held);
+  }
+
+  pub(crate) fn end_tick(&mut self) {
+    self.just_pressed.clear();
+    self.just_released.clear();
+  }
+
+  pub fn is_pressed(&self, binding: Binding) -> bool {
+    self.pressed.contains(&binding)
+  }
+
+  pub fn is_just_pressed(&self, binding: Binding) -> bool {
+    self.just_pressed.contains(&binding)
+  }
+
+  pub fn is_just_released(&self, binding: Binding) -> bool {
+    self.just_released.contains(&binding)
+  }
+}
@@ -0,0 +1,9 @@
+pub mod binding;
+pub mod controllers;
+pub mod input_map;
+pub mod input_state;
+
+pub use binding::Binding;
+pub use controllers::{Controller, Controllers};
+pub use input_map::InputMap;
+pub use input_state::InputState;
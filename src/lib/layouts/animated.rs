@@ -0,0 +1,220 @@
+use super::{stateful_layout::State, Children, StatefulLayout};
+use crate::{
+  animations::{Animation, Easing, Lerp, Tween},
+  common::Sharable,
+  models::{Box2D, Constraints},
+  Context, View,
+};
+use skia_safe::{canvas::SaveLayerRec, color_filters, BlendMode, Canvas, Color, Paint};
+use std::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Animates changes to the rect given to its child, its opacity and its color between makes of its parent over the
+// given duration in seconds along the given easing curve, instead of snapping to them
+pub struct Animated {
+  pub duration: f32,
+  pub easing: Easing,
+
+  // Between 0 and 1. The child is drawn into a layer which is blended with this opacity if less than 1.
+  pub opacity: f32,
+
+  // Draws the child in this color keeping its shape, e.g. to fade a panel from one color to another
+  pub color: Option<Color>,
+
+  pub child: Option<Sharable<View>>,
+}
+
+impl Default for Animated {
+  fn default() -> Self {
+    Self {
+      duration: 0.25f32,
+      easing: Easing::OutCubic,
+      opacity: 1f32,
+      color: None,
+      child: None,
+    }
+  }
+}
+
+impl Debug for Animated {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Animated")
+      .field("duration", &self.duration)
+      .field("easing", &self.easing)
+      .field("opacity", &self.opacity)
+      .field("color", &self.color)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Animated {
+  fn take_child_with_props(&mut self) -> AnimatedProps {
+    // Preconditions
+    assert!(self.duration >= 0f32, "duration must be a non-negative value");
+    assert!((0f32..=1f32).contains(&self.opacity), "opacity must be between 0 and 1");
+
+    AnimatedProps {
+      duration: self.duration,
+      easing: self.easing,
+      opacity: self.opacity,
+      color: self.color,
+      child: self.child.take().map(Sharable::into_shared),
+    }
+  }
+}
+
+impl StatefulLayout for Animated {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(AnimatedState::new(self.take_child_with_props())))
+  }
+
+  // Nothing to animate without a child, so a child not taken yet is a sign that these props are new
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    self.child.as_ref()?;
+    Some(Box::new(self.take_child_with_props()))
+  }
+}
+
+struct AnimatedProps {
+  duration: f32,
+  easing: Easing,
+  opacity: f32,
+  color: Option<Color>,
+  child: Option<Rc<RefCell<View>>>,
+}
+
+struct AnimatedState {
+  duration: f32,
+  easing: Easing,
+
+  // Resolved by the last layout pass, which is None before the first one
+  rect: RefCell<Option<Tween<Box2D>>>,
+
+  opacity: Tween<f32>,
+  color: Option<Tween<Color>>,
+  child: Option<Rc<RefCell<View>>>,
+}
+
+impl Debug for AnimatedState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("AnimatedState")
+      .field("duration", &self.duration)
+      .field("easing", &self.easing)
+      .field("rect", &self.rect)
+      .field("opacity", &self.opacity)
+      .field("color", &self.color)
+      .finish_non_exhaustive()
+  }
+}
+
+impl AnimatedState {
+  fn new(props: AnimatedProps) -> Self {
+    // Start at the given values without animating from anywhere
+    Self {
+      duration: props.duration,
+      easing: props.easing,
+      rect: RefCell::new(None),
+      opacity: Tween::new(props.opacity, props.opacity, 0f32),
+      color: props.color.map(|color| Tween::new(color, color, 0f32)),
+      child: props.child,
+    }
+  }
+
+  // Animates from the current value of the given tween to the given value, unless the given value is already the
+  // target of the given tween
+  fn retarget<T: Lerp + PartialEq>(&self, tween: &Tween<T>, to: T) -> Option<Tween<T>> {
+    if *tween.get_to() == to {
+      return None;
+    }
+
+    Some(Tween::new(tween.get_value(), to, self.duration).with_easing(self.easing))
+  }
+
+  fn is_layered(&self) -> bool {
+    self.opacity.get_value() < 1f32 || self.color.is_some()
+  }
+}
+
+impl State for AnimatedState {
+  fn on_update(&mut self, context: &mut Context, props: Box<dyn Any>) {
+    let Ok(props) = props.downcast::<AnimatedProps>() else {
+      return;
+    };
+
+    self.duration = props.duration;
+    self.easing = props.easing;
+
+    if let Some(opacity) = self.retarget(&self.opacity, props.opacity) {
+      self.opacity = opacity;
+    }
+
+    self.color = match (self.color.take(), props.color) {
+      (Some(color), Some(to)) => Some(self.retarget(&color, to).unwrap_or(color)),
+
+      // Nothing to animate from or to
+      (_, to) => to.map(|to| Tween::new(to, to, 0f32)),
+    };
+
+    self.child = props.child;
+    context.mark_dirty();
+  }
+
+  fn tick(&mut self, _context: &mut Context, dt: f32) {
+    if let Some(rect) = self.rect.get_mut() {
+      rect.advance(dt);
+    }
+
+    self.opacity.advance(dt);
+
+    if let Some(color) = &mut self.color {
+      color.advance(dt);
+    }
+  }
+
+  // Takes the size of the child, so that siblings move to their new rects right away while the child animates
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn arrange(&self, rect: Box2D, _child: &mut Children) -> Box2D {
+    let mut current = self.rect.borrow_mut();
+
+    *current = match current.take() {
+      Some(current) => Some(self.retarget(&current, rect).unwrap_or(current)),
+      None => Some(Tween::new(rect, rect, 0f32)),
+    };
+
+    current.as_ref().unwrap().get_value()
+  }
+
+  fn pre_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    if !self.is_layered() {
+      return;
+    }
+
+    let mut paint = Paint::default();
+    paint.set_alpha_f(self.opacity.get_value().clamp(0f32, 1f32));
+
+    if let Some(color) = &self.color {
+      paint.set_color_filter(color_filters::blend(color.get_value(), BlendMode::SrcIn));
+    }
+
+    canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.child.as_ref().map(|child| child.into())
+  }
+
+  fn post_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    if self.is_layered() {
+      canvas.restore();
+    }
+  }
+}
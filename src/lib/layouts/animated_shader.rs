@@ -0,0 +1,80 @@
+use super::{stateful_layout::State, Children, StatefulLayout};
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints},
+  nodes::ShaderNode,
+  view::IntoViewFromNode,
+  Context, View,
+};
+use std::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  mem,
+  rc::Rc,
+};
+
+// Plays the given shader by advancing its time from when it is added to the view tree. The time is kept while the
+// parent makes it again, e.g. with other uniforms.
+#[derive(Debug, Default)]
+pub struct AnimatedShader {
+  pub shader: ShaderNode,
+}
+
+impl StatefulLayout for AnimatedShader {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(AnimatedShaderState {
+      shader: mem::take(&mut self.shader),
+      time: 0f32,
+    }))
+  }
+
+  // Uniforms can change on each make of the parent. An effect not taken yet is a sign that the shader is new.
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    self.shader.effect.as_ref()?;
+    Some(Box::new(mem::take(&mut self.shader)))
+  }
+}
+
+struct AnimatedShaderState {
+  shader: ShaderNode,
+
+  // In seconds
+  time: f32,
+}
+
+impl Debug for AnimatedShaderState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("AnimatedShaderState")
+      .field("shader", &self.shader)
+      .field("time", &self.time)
+      .finish()
+  }
+}
+
+impl State for AnimatedShaderState {
+  fn on_update(&mut self, context: &mut Context, props: Box<dyn Any>) {
+    if let Ok(shader) = props.downcast::<ShaderNode>() {
+      self.shader = *shader;
+      context.mark_dirty();
+    }
+  }
+
+  fn tick(&mut self, context: &mut Context, dt: f32) {
+    self.time += dt;
+    context.mark_dirty();
+  }
+
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    ShaderNode {
+      time: self.time,
+      ..self.shader.clone()
+    }
+    .into_view()
+  }
+}
@@ -0,0 +1,139 @@
+use super::{stateful_layout::State, Children, StatefulLayout};
+use crate::{
+  common::{Clock, Sharable},
+  models::{Box2D, Constraints},
+  nodes::SpriteNode,
+  view::IntoViewFromNode,
+  Context, View,
+};
+use std::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  mem,
+  rc::Rc,
+};
+
+// Plays an animation of the sprite atlas of the given sprite. The frame of the given sprite is replaced by the frames
+// of the animation.
+#[derive(Debug, Default)]
+pub struct AnimatedSprite {
+  pub sprite: SpriteNode,
+  pub animation: String,
+}
+
+impl AnimatedSprite {
+  fn take_sprite_with_animation(&mut self) -> (SpriteNode, String) {
+    (mem::take(&mut self.sprite), mem::take(&mut self.animation))
+  }
+}
+
+impl StatefulLayout for AnimatedSprite {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    let (sprite, animation) = self.take_sprite_with_animation();
+    Rc::new(RefCell::new(AnimatedSpriteState::new(sprite, animation)))
+  }
+
+  // Nothing to play without an atlas, so an atlas not taken yet is a sign that these props are new
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    self.sprite.atlas.as_ref()?;
+    Some(Box::new(self.take_sprite_with_animation()))
+  }
+}
+
+struct AnimatedSpriteState {
+  sprite: SpriteNode,
+  animation: String,
+  frames: Vec<String>,
+  frame_index: usize,
+  looping: bool,
+  clock: Clock,
+}
+
+impl Debug for AnimatedSpriteState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("AnimatedSpriteState")
+      .field("sprite", &self.sprite)
+      .field("animation", &self.animation)
+      .field("frame_index", &self.frame_index)
+      .field("clock", &self.clock)
+      .finish_non_exhaustive()
+  }
+}
+
+impl AnimatedSpriteState {
+  fn new(sprite: SpriteNode, name: String) -> Self {
+    let animation = sprite
+      .atlas
+      .as_ref()
+      .and_then(|atlas| atlas.get_animation(&name))
+      .cloned()
+      .unwrap_or_default();
+
+    let mut clock = Clock::new(animation.frame_duration.max(f32::EPSILON));
+
+    // Nothing to play without frames
+    if animation.frames.is_empty() {
+      clock.pause();
+    }
+
+    Self {
+      sprite,
+      animation: name,
+      frames: animation.frames,
+      frame_index: 0,
+      looping: animation.looping,
+      clock,
+    }
+  }
+}
+
+impl State for AnimatedSpriteState {
+  // Restarts from the first frame whenever another animation is given, otherwise keeps playing with the given sprite
+  fn on_update(&mut self, context: &mut Context, props: Box<dyn Any>) {
+    let Ok(props) = props.downcast::<(SpriteNode, String)>() else {
+      return;
+    };
+
+    let (sprite, animation) = *props;
+
+    if animation == self.animation {
+      self.sprite = sprite;
+    } else {
+      *self = Self::new(sprite, animation);
+    }
+
+    context.mark_dirty();
+  }
+
+  fn tick(&mut self, context: &mut Context, dt: f32) {
+    self.clock.advance(dt, |clock| {
+      if self.frame_index + 1 < self.frames.len() {
+        self.frame_index += 1;
+      } else if self.looping {
+        self.frame_index = 0;
+      } else {
+        // Stay at the last frame
+        clock.pause();
+        return;
+      }
+
+      context.mark_dirty();
+    });
+  }
+
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    let frame = self.frames.get(self.frame_index)?;
+
+    SpriteNode {
+      frame: frame.to_owned(),
+      ..self.sprite.clone()
+    }
+    .into_view()
+  }
+}
@@ -1,19 +1,40 @@
-use crate::{common::Sharable, context::CONTEXT, engine::Engine, models::Box2D, View};
-use sdl2::{event::Event, image::LoadSurface, mixer, surface::Surface, video::GLProfile};
+use crate::{
+  common::Sharable,
+  context::CONTEXT,
+  engine::Engine,
+  input::InputMap,
+  models::{Backend, Box2D},
+  View,
+};
+use sdl2::{
+  event::Event,
+  image::LoadSurface,
+  mixer,
+  surface::Surface as SdlSurface,
+  video::{GLContext, GLProfile, Window},
+  Sdl,
+};
 use skia_safe::{
   gpu::{
     backend_render_targets,
     gl::{Format, FramebufferInfo},
-    surfaces, DirectContext, SurfaceOrigin,
+    surfaces as gpu_surfaces, DirectContext, SurfaceOrigin,
   },
-  Color, ColorType,
+  surfaces, Color, ColorType, Image, Surface,
 };
 use std::{
   fmt::{self, Debug, Formatter},
-  time::Instant,
+  mem, thread,
+  time::{Duration, Instant},
 };
+use tinyrand::Rand;
+use tinyrand_std::ClockSeed;
+#[cfg(windows)]
 use windows::Win32::UI::HiDpi::{SetProcessDpiAwareness, PROCESS_PER_MONITOR_DPI_AWARE};
 
+// The raster backend has no VSync to throttle the game loop, so limit it to this frame interval instead
+const RASTER_FRAME_INTERVAL: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
 pub fn run(mut app: App) {
   // Preconditions
   assert_ne!(app.size.0, 0, "size.0 must be a positive integer");
@@ -25,74 +46,45 @@ pub fn run(mut app: App) {
     SetProcessDpiAwareness(PROCESS_PER_MONITOR_DPI_AWARE).unwrap();
   }
 
+  // Initialize random number generator with a seed that can be given again later to replay this run
+  let seed = app.seed.unwrap_or_else(|| ClockSeed::default().next_u64());
+  CONTEXT.with_borrow_mut(|context| context.init_rng(seed));
+
+  // Load fonts, images and shaders so that layouts can get them from the context
+  CONTEXT.with_borrow_mut(|context| {
+    context.init_fonts();
+    context.init_images();
+    context.init_effects();
+  });
+
+  // Let the view tree query the actions bound by the app
+  CONTEXT.with_borrow_mut(|context| context.init_input(mem::take(&mut app.input_map)));
+
   // Initialize SDL
   let sdl = sdl2::init().unwrap();
 
+  // Initialize game controllers, which are opened as they are plugged in. Only a window takes input from players.
+  if app.backend == Backend::Gl {
+    let controller_subsystem = sdl.game_controller().unwrap();
+    CONTEXT.with_borrow_mut(|context| context.init_controllers(controller_subsystem));
+  }
+
   if app.play_audio {
     // Initialize audio engine
     mixer::open_audio(44100, mixer::DEFAULT_FORMAT, 2, 256).unwrap();
     CONTEXT.with_borrow_mut(|context| context.init_audio());
   }
 
-  // Initialize SDL video subsystem
-  let vid_subsys = sdl.video().unwrap();
-
-  // Configure OpenGL attributes
-  let gl_attr = vid_subsys.gl_attr();
-  gl_attr.set_red_size(8);
-  gl_attr.set_green_size(8);
-  gl_attr.set_blue_size(8);
-  gl_attr.set_context_flags().forward_compatible().set();
-  gl_attr.set_context_no_error(true);
-  gl_attr.set_context_profile(GLProfile::Core);
-  gl_attr.set_context_version(4, 6);
-  gl_attr.set_depth_size(24);
-
-  // Prepare a window
-  let mut window = vid_subsys
-    .window(app.title, app.size.0, app.size.1)
-    .opengl()
-    .allow_highdpi()
-    .position_centered()
-    .build()
-    .unwrap();
-  window.set_icon(Surface::from_file("assets/images/favicon.png").unwrap());
-
-  // Initialize OpenGL context and make it current in this thread
-  let _gl_ctx = window.gl_create_context().unwrap();
-  gl::load_with(|name| vid_subsys.gl_get_proc_address(name) as *const _);
-
-  // Enable VSync
-  vid_subsys.gl_set_swap_interval(1).unwrap();
-
-  // Initialize Skia engine on top of the OpenGL context
-  let mut gr_ctx = DirectContext::new_gl(None, None).unwrap();
-  let render_target = backend_render_targets::make_gl(
-    (app.size.0 as _, app.size.1 as _),
-    0,
-    8,
-    FramebufferInfo {
-      fboid: 0,
-      format: Format::RGBA8.into(),
-      ..Default::default()
-    },
-  );
-  let mut surface = surfaces::wrap_backend_render_target(
-    &mut gr_ctx,
-    &render_target,
-    SurfaceOrigin::BottomLeft,
-    ColorType::RGBA8888,
-    None,
-    None,
-  )
-  .unwrap();
-
-  // Get the canvas from the Skia engine to start drawing and have fun
-  let canvas = surface.canvas();
+  // Prepare a surface for Skia engine to draw on
+  let mut renderer = match app.backend {
+    Backend::Gl => Renderer::new_gl(&sdl, &app),
+    Backend::Raster { .. } => Renderer::new_raster(&app),
+  };
 
   // Game loop
   let mut event_pump = sdl.event_pump().unwrap();
   let mut prev = Instant::now();
+  let mut frame_count = 0;
   CONTEXT.with_borrow_mut(|context| {
     loop {
       // Input
@@ -101,62 +93,63 @@ pub fn run(mut app: App) {
           return;
         }
 
-        match &mut app.child {
-          Some(Sharable::Owned(child)) => Engine::on_event(child, context, &event),
-          Some(Sharable::Shared(child)) => Engine::on_event(&mut child.borrow_mut(), context, &event),
-          None => {},
+        if let Some(child) = &mut app.child {
+          Engine::on_event_in_root(child, context, &event);
         }
       }
 
-      // Before process
+      // Process
       let now = Instant::now();
-      let mut dt_left = (now - prev).as_secs_f32();
+      let dt = (now - prev).as_secs_f32();
       prev = now;
-      let mut ticks_left = 8; // 8 max ticks per frame
-
-      // Process
-      while ticks_left > 0 && dt_left > 0f32 {
-        let dt = dt_left.min(1f32 / 120f32); // 120 ticks per second
-
-        match &mut app.child {
-          Some(Sharable::Owned(child)) => Engine::tick(child, context, dt),
-          Some(Sharable::Shared(child)) => Engine::tick(&mut child.borrow_mut(), context, dt),
-          None => {},
-        }
 
-        dt_left -= dt;
-        ticks_left -= 1;
+      if let Some(child) = &mut app.child {
+        Engine::advance_root(child, context, dt);
       }
 
       // Output
       if let Some(child) = &mut app.child {
+        // Get the canvas from the Skia engine to start drawing and have fun
+        let canvas = renderer.get_surface().canvas();
+
         // Clear the previous frame before drawing to avoid unwanted artifacts
         canvas.clear(app.color);
 
-        // Draw the whole view tree given
-        let size = (app.size.0 as _, app.size.1 as _);
-        match child {
-          Sharable::Owned(child) => context.get_engine().draw_view(
-            child,
-            canvas,
-            Box2D {
-              position: (0f32, 0f32),
-              size,
-            },
-          ),
-          Sharable::Shared(child) => context.get_engine().draw_view(
-            &mut child.borrow_mut(),
-            canvas,
-            Box2D {
-              position: (0f32, 0f32),
-              size,
-            },
-          ),
+        let rect = Box2D {
+          position: (0f32, 0f32),
+          size: (app.size.0 as _, app.size.1 as _),
         };
 
-        // Present the drawn canvas to the window
-        gr_ctx.flush_and_submit();
-        window.gl_swap_window();
+        // Resolve the rect of each view in the whole view tree given
+        Engine::layout_root(child, context, rect);
+
+        // Draw the whole view tree given
+        Engine::draw_root(child, context, canvas, rect);
+
+        renderer.present();
+
+        if let Some(on_frame) = &mut app.on_frame {
+          on_frame(&renderer.get_surface().image_snapshot());
+        }
+      }
+
+      // Drop the states of layouts which are no longer in the view tree
+      Engine::sweep(context);
+
+      frame_count += 1;
+
+      if let Backend::Raster {
+        frame_count: Some(max_frame_count),
+      } = app.backend
+      {
+        if frame_count >= max_frame_count {
+          return;
+        }
+      }
+
+      if let Renderer::Raster { .. } = renderer {
+        // Sleep for the rest of this frame to avoid spinning the CPU
+        thread::sleep(RASTER_FRAME_INTERVAL.saturating_sub(now.elapsed()));
       }
     }
   });
@@ -167,12 +160,22 @@ pub fn run(mut app: App) {
   }
 }
 
+type OnFrame = Box<dyn FnMut(&Image)>;
+
 #[derive(Default)]
 pub struct App<'a> {
   pub title: &'a str,
   pub size: (u32, u32),
   pub color: Color,
   pub play_audio: bool,
+  pub backend: Backend,
+  pub seed: Option<u64>,
+  pub input_map: InputMap,
+
+  // Called with each frame once it is drawn, e.g. to save the frames of the raster backend. Reading frames back from
+  // the GPU is slow.
+  pub on_frame: Option<OnFrame>,
+
   pub child: Option<Sharable<View>>,
 }
 
@@ -184,6 +187,107 @@ impl<'a> Debug for App<'a> {
       .field("size", &self.size)
       .field("color", &self.color)
       .field("play_audio", &self.play_audio)
+      .field("backend", &self.backend)
+      .field("seed", &self.seed)
+      .field("input_map", &self.input_map)
       .finish_non_exhaustive()
   }
 }
+
+// Fields are dropped in declaration order, so the surface must be dropped before the contexts it is drawn with
+enum Renderer {
+  Gl {
+    surface: Surface,
+    gr_ctx: DirectContext,
+    _gl_ctx: GLContext,
+    window: Window,
+  },
+  Raster {
+    surface: Surface,
+  },
+}
+
+impl Renderer {
+  fn new_gl(sdl: &Sdl, app: &App) -> Self {
+    // Initialize SDL video subsystem
+    let vid_subsys = sdl.video().unwrap();
+
+    // Configure OpenGL attributes
+    let gl_attr = vid_subsys.gl_attr();
+    gl_attr.set_red_size(8);
+    gl_attr.set_green_size(8);
+    gl_attr.set_blue_size(8);
+    gl_attr.set_context_flags().forward_compatible().set();
+    gl_attr.set_context_no_error(true);
+    gl_attr.set_context_profile(GLProfile::Core);
+    gl_attr.set_context_version(4, 6);
+    gl_attr.set_depth_size(24);
+
+    // Prepare a window
+    let mut window = vid_subsys
+      .window(app.title, app.size.0, app.size.1)
+      .opengl()
+      .allow_highdpi()
+      .position_centered()
+      .build()
+      .unwrap();
+    window.set_icon(SdlSurface::from_file("assets/images/favicon.png").unwrap());
+
+    // Initialize OpenGL context and make it current in this thread
+    let gl_ctx = window.gl_create_context().unwrap();
+    gl::load_with(|name| vid_subsys.gl_get_proc_address(name) as *const _);
+
+    // Enable VSync
+    vid_subsys.gl_set_swap_interval(1).unwrap();
+
+    // Initialize Skia engine on top of the OpenGL context
+    let mut gr_ctx = DirectContext::new_gl(None, None).unwrap();
+    let render_target = backend_render_targets::make_gl(
+      (app.size.0 as _, app.size.1 as _),
+      0,
+      8,
+      FramebufferInfo {
+        fboid: 0,
+        format: Format::RGBA8.into(),
+        ..Default::default()
+      },
+    );
+    let surface = gpu_surfaces::wrap_backend_render_target(
+      &mut gr_ctx,
+      &render_target,
+      SurfaceOrigin::BottomLeft,
+      ColorType::RGBA8888,
+      None,
+      None,
+    )
+    .unwrap();
+
+    Self::Gl {
+      surface,
+      gr_ctx,
+      _gl_ctx: gl_ctx,
+      window,
+    }
+  }
+
+  fn new_raster(app: &App) -> Self {
+    // Initialize Skia engine on top of a CPU memory buffer
+    Self::Raster {
+      surface: surfaces::raster_n32_premul((app.size.0 as _, app.size.1 as _)).unwrap(),
+    }
+  }
+
+  fn get_surface(&mut self) -> &mut Surface {
+    match self {
+      Self::Gl { surface, .. } | Self::Raster { surface } => surface,
+    }
+  }
+
+  fn present(&mut self) {
+    if let Self::Gl { gr_ctx, window, .. } = self {
+      // Present the drawn canvas to the window
+      gr_ctx.flush_and_submit();
+      window.gl_swap_window();
+    }
+  }
+}
@@ -0,0 +1,58 @@
+use crate::{
+  common::Sharable,
+  models::{Constraints, ViewId},
+  Context, Engine, View,
+};
+use std::fmt::{self, Debug, Formatter};
+
+// The children made by a layout, which the layout can measure before positioning them
+pub struct Children<'a> {
+  parent: ViewId,
+  views: &'a mut [Sharable<View>],
+  context: &'a mut Context,
+}
+
+impl<'a> Debug for Children<'a> {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Children")
+      .field("parent", &self.parent)
+      .field("len", &self.views.len())
+      .finish_non_exhaustive()
+  }
+}
+
+impl<'a> Children<'a> {
+  pub(crate) fn new(parent: ViewId, views: &'a mut [Sharable<View>], context: &'a mut Context) -> Self {
+    Self { parent, views, context }
+  }
+
+  pub fn len(&self) -> usize {
+    self.views.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.views.is_empty()
+  }
+
+  // Measures the child at the given index, which is cached until the next layout pass for the same constraints
+  pub fn measure(&mut self, index: usize, constraints: Constraints) -> (f32, f32) {
+    Engine::measure_child(&mut self.views[index], self.parent, index, self.context, constraints)
+  }
+
+  // Measures the only child of a single child layout, or takes the smallest size allowed without it
+  pub fn measure_single(&mut self, constraints: Constraints) -> (f32, f32) {
+    if self.is_empty() {
+      constraints.constrain((0f32, 0f32))
+    } else {
+      self.measure(0, constraints)
+    }
+  }
+
+  pub fn with_view<R>(&self, index: usize, f: impl FnOnce(&View) -> R) -> R {
+    match &self.views[index] {
+      Sharable::Owned(view) => f(view),
+      Sharable::Shared(view) => f(&view.borrow()),
+    }
+  }
+}
@@ -0,0 +1,110 @@
+use super::{Children, StatelessLayout};
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints},
+  View,
+};
+use skia_safe::{Canvas, ClipOp, Matrix, Path, RRect, Rect, Vector};
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+#[derive(Clone, Debug, Default)]
+pub enum ClipShape {
+  #[default]
+  Rect,
+
+  // Radii of the top left, top right, bottom right and bottom left corners along x and y axes
+  RRect([(f32, f32); 4]),
+
+  // An arbitrary path whose origin is placed at the top left corner of the rect
+  Path(Path),
+}
+
+impl ClipShape {
+  // Clips the given canvas to this shape in the given rect until the canvas is restored
+  pub(super) fn clip(&self, canvas: &Canvas, rect: Box2D, anti_alias: bool) {
+    let bounds = Rect::from_xywh(rect.position.0, rect.position.1, rect.size.0, rect.size.1);
+
+    match self {
+      Self::Rect => {
+        canvas.clip_rect(bounds, ClipOp::Intersect, anti_alias);
+      },
+      Self::RRect(radii) => {
+        canvas.clip_rrect(
+          RRect::new_rect_radii(bounds, &radii.map(Vector::from)),
+          ClipOp::Intersect,
+          anti_alias,
+        );
+      },
+      Self::Path(path) => {
+        canvas.clip_path(
+          &path.with_transform(&Matrix::translate(rect.position)),
+          ClipOp::Intersect,
+          anti_alias,
+        );
+      },
+    }
+  }
+}
+
+// Only draws the part of its child inside the given shape
+pub struct Clip {
+  pub shape: ClipShape,
+  pub anti_alias: bool,
+  pub child: Option<Rc<RefCell<View>>>,
+}
+
+impl Default for Clip {
+  fn default() -> Self {
+    Self {
+      shape: ClipShape::Rect,
+      anti_alias: true,
+      child: None,
+    }
+  }
+}
+
+impl Debug for Clip {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Clip")
+      .field("shape", &self.shape)
+      .field("anti_alias", &self.anti_alias)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Clip {
+  pub fn new(child: Option<Sharable<View>>) -> Self {
+    Self {
+      child: child.map(Sharable::into_shared),
+      ..Default::default()
+    }
+  }
+}
+
+impl StatelessLayout for Clip {
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn is_clipping(&self) -> bool {
+    true
+  }
+
+  fn pre_draw(&self, canvas: &Canvas, constraint: Box2D) {
+    canvas.save();
+    self.shape.clip(canvas, constraint, self.anti_alias);
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.child.as_ref().map(|child| child.into())
+  }
+
+  fn post_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    canvas.restore();
+  }
+}
@@ -0,0 +1,287 @@
+use super::{Children, MultiChildLayout};
+use crate::{
+  common::Sharable,
+  models::{Axis, Box2D, Constraints, Overflow},
+  View,
+};
+use skia_safe::{Canvas, Rect};
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// How children are placed along the main axis when there is space left in a flex layout
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MainAxisAlignment {
+  #[default]
+  Start,
+  End,
+  Center,
+  SpaceBetween,
+  SpaceAround,
+  SpaceEvenly,
+}
+
+// How each child is placed along the cross axis of a flex layout
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CrossAxisAlignment {
+  #[default]
+  Start,
+  End,
+  Center,
+  Stretch,
+}
+
+// Flexible children share the space left by the other children in proportion to their flex
+#[derive(Default)]
+pub struct FlexChild {
+  pub flex: f32,
+  pub child: Option<Rc<RefCell<View>>>,
+}
+
+impl Debug for FlexChild {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("FlexChild")
+      .field("flex", &self.flex)
+      .finish_non_exhaustive()
+  }
+}
+
+impl FlexChild {
+  pub fn new(child: Option<Sharable<View>>) -> Self {
+    Self::flexible(0f32, child)
+  }
+
+  pub fn flexible(flex: f32, child: Option<Sharable<View>>) -> Self {
+    // Preconditions
+    assert!(flex >= 0f32, "flex must be a non-negative number");
+
+    Self {
+      flex,
+      child: child.map(Sharable::into_shared),
+    }
+  }
+}
+
+impl From<Option<Sharable<View>>> for FlexChild {
+  fn from(child: Option<Sharable<View>>) -> Self {
+    Self::new(child)
+  }
+}
+
+#[derive(Default)]
+pub struct Flex {
+  pub axis: Axis,
+  pub main_axis_alignment: MainAxisAlignment,
+  pub cross_axis_alignment: CrossAxisAlignment,
+  pub spacing: f32,
+  pub overflow: Overflow,
+  pub children: Vec<FlexChild>,
+}
+
+impl Debug for Flex {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Flex")
+      .field("axis", &self.axis)
+      .field("main_axis_alignment", &self.main_axis_alignment)
+      .field("cross_axis_alignment", &self.cross_axis_alignment)
+      .field("spacing", &self.spacing)
+      .field("overflow", &self.overflow)
+      .field("children", &self.children)
+      .finish()
+  }
+}
+
+impl Flex {
+  // Lays out the given children from left to right
+  pub fn row(children: Vec<FlexChild>) -> Self {
+    Self {
+      axis: Axis::Horizontal,
+      children,
+      ..Default::default()
+    }
+  }
+
+  // Lays out the given children from top to bottom
+  pub fn column(children: Vec<FlexChild>) -> Self {
+    Self {
+      axis: Axis::Vertical,
+      children,
+      ..Default::default()
+    }
+  }
+
+  fn get_flexes(&self) -> Vec<f32> {
+    self
+      .children
+      .iter()
+      .filter(|child| child.child.is_some())
+      .map(|child| child.flex)
+      .collect()
+  }
+
+  // Inflexible children are measured first, then flexible children are given the space left
+  fn measure_children(&self, constraints: Constraints, children: &mut Children) -> Vec<(f32, f32)> {
+    let axis = self.axis;
+    let flexes = self.get_flexes();
+    let max_main = axis.get_main(constraints.max);
+    let max_cross = axis.get_cross(constraints.max);
+    let is_bounded = max_main < f32::MAX;
+
+    let min_cross = if self.cross_axis_alignment == CrossAxisAlignment::Stretch && max_cross < f32::MAX {
+      max_cross
+    } else {
+      0f32
+    };
+
+    let mut sizes = vec![(0f32, 0f32); flexes.len()];
+    let mut used = self.spacing * flexes.len().saturating_sub(1) as f32;
+
+    for (index, &flex) in flexes.iter().enumerate() {
+      if flex > 0f32 && is_bounded {
+        continue;
+      }
+
+      let size = children.measure(
+        index,
+        Constraints {
+          min: axis.pack(0f32, min_cross),
+          max: axis.pack(max_main, max_cross),
+        },
+      );
+
+      used += axis.get_main(size);
+      sizes[index] = size;
+    }
+
+    if !is_bounded {
+      return sizes;
+    }
+
+    let total_flex = flexes.iter().sum::<f32>();
+    let free = (max_main - used).max(0f32);
+
+    for (index, &flex) in flexes.iter().enumerate() {
+      if flex == 0f32 {
+        continue;
+      }
+
+      let main = free * flex / total_flex;
+
+      sizes[index] = children.measure(
+        index,
+        Constraints {
+          min: axis.pack(main, min_cross),
+          max: axis.pack(main, max_cross),
+        },
+      );
+    }
+
+    sizes
+  }
+
+  fn get_main_extent(&self, sizes: &[(f32, f32)]) -> f32 {
+    let spacing = self.spacing * sizes.len().saturating_sub(1) as f32;
+    sizes.iter().map(|&size| self.axis.get_main(size)).sum::<f32>() + spacing
+  }
+}
+
+impl MultiChildLayout for Flex {
+  // Wraps the children tightly along the main axis unless some of them are flexible
+  fn measure(&self, constraints: Constraints, children: &mut Children) -> (f32, f32) {
+    let axis = self.axis;
+    let sizes = self.measure_children(constraints, children);
+    let max_main = axis.get_main(constraints.max);
+
+    let main = if max_main < f32::MAX && self.get_flexes().iter().any(|&flex| flex > 0f32) {
+      max_main
+    } else {
+      self.get_main_extent(&sizes)
+    };
+
+    let cross = sizes.iter().map(|&size| axis.get_cross(size)).fold(0f32, f32::max);
+    constraints.constrain(axis.pack(main, cross))
+  }
+
+  fn arrange(&self, rect: Box2D, children: &mut Children) -> Vec<Box2D> {
+    let axis = self.axis;
+    let sizes = self.measure_children(Constraints::loose(rect.size), children);
+    let free = axis.get_main(rect.size) - self.get_main_extent(&sizes);
+    let gap_count = sizes.len() as f32;
+
+    let (leading, between) = match self.main_axis_alignment {
+      MainAxisAlignment::Start => (0f32, self.spacing),
+      MainAxisAlignment::End => (free, self.spacing),
+      MainAxisAlignment::Center => (free * 0.5f32, self.spacing),
+      MainAxisAlignment::SpaceBetween if sizes.len() > 1 => (0f32, self.spacing + free.max(0f32) / (gap_count - 1f32)),
+      MainAxisAlignment::SpaceBetween => (0f32, self.spacing),
+      MainAxisAlignment::SpaceAround => {
+        let gap = free.max(0f32) / gap_count;
+        (gap * 0.5f32, self.spacing + gap)
+      },
+      MainAxisAlignment::SpaceEvenly => {
+        let gap = free.max(0f32) / (gap_count + 1f32);
+        (gap, self.spacing + gap)
+      },
+    };
+
+    let cross_extent = axis.get_cross(rect.size);
+    let mut main = axis.get_main(rect.position) + leading;
+
+    sizes
+      .into_iter()
+      .map(|size| {
+        let cross = axis.get_cross(size);
+
+        let cross_offset = match self.cross_axis_alignment {
+          CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0f32,
+          CrossAxisAlignment::End => cross_extent - cross,
+          CrossAxisAlignment::Center => (cross_extent - cross) * 0.5f32,
+        };
+
+        let child_rect = Box2D {
+          position: axis.pack(main, axis.get_cross(rect.position) + cross_offset),
+          size,
+        };
+
+        main += axis.get_main(size) + between;
+        child_rect
+      })
+      .collect()
+  }
+
+  fn pre_draw(&self, canvas: &Canvas, constraint: Box2D) {
+    if self.overflow == Overflow::Clip {
+      canvas.save();
+
+      canvas.clip_rect(
+        Rect::from_xywh(
+          constraint.position.0,
+          constraint.position.1,
+          constraint.size.0,
+          constraint.size.1,
+        ),
+        None,
+        None,
+      );
+    }
+  }
+
+  fn make(&self, _constraint: Box2D) -> Vec<Sharable<View>> {
+    self
+      .children
+      .iter()
+      .filter_map(|child| child.child.as_ref())
+      .map(|child| child.into())
+      .collect()
+  }
+
+  fn post_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    if self.overflow == Overflow::Clip {
+      canvas.restore();
+    }
+  }
+}
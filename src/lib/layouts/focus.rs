@@ -0,0 +1,144 @@
+use super::{
+  stateful_layout::{self, State},
+  Children, StatefulLayout,
+};
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints},
+  Context, View,
+};
+use sdl2::{event::Event, mouse::MouseButton};
+use std::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+type OnFocusChange = Box<dyn FnMut(&mut Context, bool)>;
+type OnKey = Box<dyn FnMut(&mut Context, &Event)>;
+
+// Makes its child focusable. Pressing it with the left mouse button or a finger also focuses it.
+#[derive(Default)]
+pub struct Focus {
+  // Requests focus as soon as it is added to the view tree
+  pub autofocus: bool,
+
+  // Called with whether it has focus whenever that changes
+  pub on_focus_change: Option<OnFocusChange>,
+
+  // Called with key events while it has focus, before its ancestors and the rest of the view tree. Call
+  // context.stop_propagation() for the keys handled here, which also stops arrow keys and Tab from moving focus away.
+  pub on_key: Option<OnKey>,
+
+  pub child: Option<Sharable<View>>,
+}
+
+impl Debug for Focus {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Focus")
+      .field("autofocus", &self.autofocus)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Focus {
+  fn take_child_with_props(&mut self) -> FocusProps {
+    FocusProps {
+      on_focus_change: self.on_focus_change.take(),
+      on_key: self.on_key.take(),
+      child: self.child.take().map(Sharable::into_shared),
+    }
+  }
+}
+
+impl StatefulLayout for Focus {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(FocusState {
+      autofocus: self.autofocus,
+      props: self.take_child_with_props(),
+    }))
+  }
+
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    stateful_layout::take_child_with_props(self.child.is_some(), || self.take_child_with_props())
+  }
+}
+
+struct FocusProps {
+  on_focus_change: Option<OnFocusChange>,
+  on_key: Option<OnKey>,
+  child: Option<Rc<RefCell<View>>>,
+}
+
+struct FocusState {
+  autofocus: bool,
+  props: FocusProps,
+}
+
+impl Debug for FocusState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("FocusState")
+      .field("autofocus", &self.autofocus)
+      .finish_non_exhaustive()
+  }
+}
+
+impl State for FocusState {
+  fn on_mount(&mut self, context: &mut Context) {
+    if self.autofocus {
+      context.request_focus();
+    }
+  }
+
+  fn on_update(&mut self, context: &mut Context, props: Box<dyn Any>) {
+    if let Ok(props) = props.downcast::<FocusProps>() {
+      self.props = *props;
+      context.mark_dirty();
+    }
+  }
+
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    match event {
+      Event::MouseButtonDown {
+        mouse_btn: MouseButton::Left,
+        ..
+      }
+      | Event::FingerDown { .. } => context.request_focus(),
+      Event::KeyDown { .. } | Event::KeyUp { .. } | Event::TextInput { .. } | Event::TextEditing { .. }
+        if context.has_focus() =>
+      {
+        if let Some(on_key) = &mut self.props.on_key {
+          on_key(context, event);
+        }
+      },
+      _ => {},
+    }
+  }
+
+  fn on_focus_gained(&mut self, context: &mut Context) {
+    if let Some(on_focus_change) = &mut self.props.on_focus_change {
+      on_focus_change(context, true);
+    }
+  }
+
+  fn on_focus_lost(&mut self, context: &mut Context) {
+    if let Some(on_focus_change) = &mut self.props.on_focus_change {
+      on_focus_change(context, false);
+    }
+  }
+
+  fn is_focusable(&self) -> bool {
+    true
+  }
+
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.props.child.as_ref().map(|child| child.into())
+  }
+}
@@ -0,0 +1,75 @@
+use super::{stateful_layout::State, Children, StatefulLayout};
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints},
+  Context, View,
+};
+use std::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Keeps focus traversal with Tab, Shift+Tab and arrow keys among the focusable descendants of its child, e.g. so that
+// moving focus around a menu never lands on the game behind it
+#[derive(Default)]
+pub struct FocusScope {
+  pub child: Option<Sharable<View>>,
+}
+
+impl Debug for FocusScope {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt.debug_struct("FocusScope").finish_non_exhaustive()
+  }
+}
+
+impl FocusScope {
+  fn take_child(&mut self) -> Option<Rc<RefCell<View>>> {
+    self.child.take().map(Sharable::into_shared)
+  }
+}
+
+impl StatefulLayout for FocusScope {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(FocusScopeState {
+      child: self.take_child(),
+    }))
+  }
+
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    self.child.as_ref()?;
+    Some(Box::new(self.take_child()))
+  }
+}
+
+struct FocusScopeState {
+  child: Option<Rc<RefCell<View>>>,
+}
+
+impl Debug for FocusScopeState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt.debug_struct("FocusScopeState").finish_non_exhaustive()
+  }
+}
+
+impl State for FocusScopeState {
+  fn on_update(&mut self, context: &mut Context, props: Box<dyn Any>) {
+    if let Ok(child) = props.downcast::<Option<Rc<RefCell<View>>>>() {
+      self.child = *child;
+      context.mark_dirty();
+    }
+  }
+
+  fn is_focus_scope(&self) -> bool {
+    true
+  }
+
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.child.as_ref().map(|child| child.into())
+  }
+}
@@ -0,0 +1,421 @@
+use super::{
+  stateful_layout::{self, State},
+  Children, StatefulLayout,
+};
+use crate::{
+  common::{Sharable, VelocityTracker},
+  models::{Box2D, Constraints, Direction},
+  Context, View,
+};
+use sdl2::{event::Event, mouse::MouseButton};
+use std::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// SDL also makes mouse events from touches with this mouse id, which are ignored since the touches are handled already
+const TOUCH_MOUSE_ID: u32 = u32::MAX;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GestureThresholds {
+  // Distance in pixels the pointer can move while pressed before a tap or long press turns into a drag
+  pub slop: f32,
+
+  // Most seconds between two taps which make a double tap
+  pub double_tap_interval: f32,
+
+  // Seconds the pointer has to stay pressed without dragging to make a long press
+  pub long_press_duration: f32,
+
+  // A drag released at least this fast in pixels per second after moving at least this far in pixels along its main
+  // axis is also a swipe
+  pub swipe_velocity: f32,
+  pub swipe_distance: f32,
+}
+
+impl Default for GestureThresholds {
+  fn default() -> Self {
+    Self {
+      slop: 8f32,
+      double_tap_interval: 0.3f32,
+      long_press_duration: 0.5f32,
+      swipe_velocity: 300f32,
+      swipe_distance: 40f32,
+    }
+  }
+}
+
+// Positions are in the same space as the rect of the gesture detector
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Drag {
+  pub position: (f32, f32),
+
+  // Distance moved since the last drag callback
+  pub delta: (f32, f32),
+
+  // Pixels per second
+  pub velocity: (f32, f32),
+}
+
+type OnPoint = Box<dyn FnMut(&mut Context, (f32, f32))>;
+type OnDrag = Box<dyn FnMut(&mut Context, Drag)>;
+type OnSwipe = Box<dyn FnMut(&mut Context, Direction)>;
+
+// Recognizes gestures made by the left mouse button or a finger pressed on its child. Only the first pointer pressed is
+// tracked until it is released. A second tap within the double tap interval calls on_double_tap() instead of on_tap().
+#[derive(Default)]
+pub struct GestureDetector {
+  pub thresholds: GestureThresholds,
+  pub on_tap: Option<OnPoint>,
+  pub on_double_tap: Option<OnPoint>,
+  pub on_long_press: Option<OnPoint>,
+  pub on_drag_start: Option<OnDrag>,
+  pub on_drag_update: Option<OnDrag>,
+  pub on_drag_end: Option<OnDrag>,
+
+  // Called after on_drag_end() when the drag is fast and long enough
+  pub on_swipe: Option<OnSwipe>,
+
+  pub child: Option<Sharable<View>>,
+}
+
+impl Debug for GestureDetector {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("GestureDetector")
+      .field("thresholds", &self.thresholds)
+      .finish_non_exhaustive()
+  }
+}
+
+impl GestureDetector {
+  fn take_child_with_props(&mut self) -> GestureDetectorProps {
+    // Preconditions
+    assert!(
+      self.thresholds.slop >= 0f32,
+      "thresholds.slop must be a non-negative value"
+    );
+    assert!(
+      self.thresholds.double_tap_interval >= 0f32,
+      "thresholds.double_tap_interval must be a non-negative value"
+    );
+    assert!(
+      self.thresholds.long_press_duration > 0f32,
+      "thresholds.long_press_duration must be a positive value"
+    );
+
+    GestureDetectorProps {
+      thresholds: self.thresholds,
+      on_tap: self.on_tap.take(),
+      on_double_tap: self.on_double_tap.take(),
+      on_long_press: self.on_long_press.take(),
+      on_drag_start: self.on_drag_start.take(),
+      on_drag_update: self.on_drag_update.take(),
+      on_drag_end: self.on_drag_end.take(),
+      on_swipe: self.on_swipe.take(),
+      child: self.child.take().map(Sharable::into_shared),
+    }
+  }
+}
+
+impl StatefulLayout for GestureDetector {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(GestureDetectorState {
+      props: self.take_child_with_props(),
+      time: 0f32,
+      pointer: None,
+      last_tap_time: None,
+    }))
+  }
+
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    stateful_layout::take_child_with_props(self.child.is_some(), || self.take_child_with_props())
+  }
+}
+
+struct GestureDetectorProps {
+  thresholds: GestureThresholds,
+  on_tap: Option<OnPoint>,
+  on_double_tap: Option<OnPoint>,
+  on_long_press: Option<OnPoint>,
+  on_drag_start: Option<OnDrag>,
+  on_drag_update: Option<OnDrag>,
+  on_drag_end: Option<OnDrag>,
+  on_swipe: Option<OnSwipe>,
+  child: Option<Rc<RefCell<View>>>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum PointerSource {
+  Mouse,
+  Finger(i64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Pointer {
+  source: PointerSource,
+  start_position: (f32, f32),
+  start_time: f32,
+  position: (f32, f32),
+  is_dragging: bool,
+  is_long_pressed: bool,
+
+  velocity: VelocityTracker,
+}
+
+struct GestureDetectorState {
+  props: GestureDetectorProps,
+
+  // Seconds ticked since this state is made
+  time: f32,
+
+  pointer: Option<Pointer>,
+  last_tap_time: Option<f32>,
+}
+
+impl Debug for GestureDetectorState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("GestureDetectorState")
+      .field("thresholds", &self.props.thresholds)
+      .field("time", &self.time)
+      .field("pointer", &self.pointer)
+      .field("last_tap_time", &self.last_tap_time)
+      .finish_non_exhaustive()
+  }
+}
+
+impl GestureDetectorState {
+  fn press(&mut self, source: PointerSource, position: (f32, f32)) {
+    if self.pointer.is_some() {
+      return;
+    }
+
+    self.pointer = Some(Pointer {
+      source,
+      start_position: position,
+      start_time: self.time,
+      position,
+      is_dragging: false,
+      is_long_pressed: false,
+      velocity: VelocityTracker::new(self.time, position),
+    });
+  }
+
+  fn move_to(&mut self, context: &mut Context, source: PointerSource, position: (f32, f32)) {
+    let Some(pointer) = &mut self.pointer else {
+      return;
+    };
+
+    if pointer.source != source || pointer.position == position {
+      return;
+    }
+
+    let delta = (position.0 - pointer.position.0, position.1 - pointer.position.1);
+    pointer.position = position;
+    pointer.velocity.add_sample(self.time, position);
+    let velocity = pointer.velocity.get_velocity(self.time);
+
+    if pointer.is_dragging {
+      let drag = Drag {
+        position,
+        delta,
+        velocity,
+      };
+
+      if let Some(on_drag_update) = &mut self.props.on_drag_update {
+        on_drag_update(context, drag);
+      }
+
+      return;
+    }
+
+    let moved = (
+      position.0 - pointer.start_position.0,
+      position.1 - pointer.start_position.1,
+    );
+
+    // A long press stays a long press until released
+    if pointer.is_long_pressed || moved.0.hypot(moved.1) <= self.props.thresholds.slop {
+      return;
+    }
+
+    pointer.is_dragging = true;
+
+    let start = Drag {
+      position: pointer.start_position,
+      ..Default::default()
+    };
+
+    // Nothing moved within the slop is lost
+    let drag = Drag {
+      position,
+      delta: moved,
+      velocity,
+    };
+
+    if let Some(on_drag_start) = &mut self.props.on_drag_start {
+      on_drag_start(context, start);
+    }
+
+    if let Some(on_drag_update) = &mut self.props.on_drag_update {
+      on_drag_update(context, drag);
+    }
+  }
+
+  fn release(&mut self, context: &mut Context, source: PointerSource, position: (f32, f32)) {
+    if self.pointer.as_ref().map(|pointer| pointer.source) != Some(source) {
+      return;
+    }
+
+    self.move_to(context, source, position);
+
+    let Some(pointer) = self.pointer.take() else {
+      return;
+    };
+
+    if pointer.is_dragging {
+      self.end_drag(context, pointer);
+    } else if !pointer.is_long_pressed {
+      self.tap(context, pointer.position);
+    }
+  }
+
+  fn tap(&mut self, context: &mut Context, position: (f32, f32)) {
+    let is_double_tap = self
+      .last_tap_time
+      .is_some_and(|last_tap_time| self.time - last_tap_time <= self.props.thresholds.double_tap_interval);
+
+    // A third tap starts over
+    if is_double_tap {
+      self.last_tap_time = None;
+
+      if let Some(on_double_tap) = &mut self.props.on_double_tap {
+        on_double_tap(context, position);
+      }
+    } else {
+      self.last_tap_time = Some(self.time);
+
+      if let Some(on_tap) = &mut self.props.on_tap {
+        on_tap(context, position);
+      }
+    }
+  }
+
+  fn end_drag(&mut self, context: &mut Context, pointer: Pointer) {
+    let velocity = pointer.velocity.get_velocity(self.time);
+
+    let drag = Drag {
+      position: pointer.position,
+      delta: (0f32, 0f32),
+      velocity,
+    };
+
+    if let Some(on_drag_end) = &mut self.props.on_drag_end {
+      on_drag_end(context, drag);
+    }
+
+    let moved = (
+      pointer.position.0 - pointer.start_position.0,
+      pointer.position.1 - pointer.start_position.1,
+    );
+
+    // The main axis is the one moved along the most
+    let (distance, velocity, direction) = if moved.0.abs() >= moved.1.abs() {
+      let direction = if moved.0 > 0f32 {
+        Direction::Right
+      } else {
+        Direction::Left
+      };
+
+      (moved.0, velocity.0, direction)
+    } else {
+      let direction = if moved.1 > 0f32 { Direction::Down } else { Direction::Up };
+
+      (moved.1, velocity.1, direction)
+    };
+
+    let thresholds = self.props.thresholds;
+
+    // Flicking back at the end of a drag is not a swipe
+    if distance.abs() < thresholds.swipe_distance
+      || velocity.abs() < thresholds.swipe_velocity
+      || distance.signum() != velocity.signum()
+    {
+      return;
+    }
+
+    if let Some(on_swipe) = &mut self.props.on_swipe {
+      on_swipe(context, direction);
+    }
+  }
+}
+
+impl State for GestureDetectorState {
+  fn on_update(&mut self, context: &mut Context, props: Box<dyn Any>) {
+    if let Ok(props) = props.downcast::<GestureDetectorProps>() {
+      self.props = *props;
+      context.mark_dirty();
+    }
+  }
+
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    let Some(position) = context.get_pointer_position() else {
+      return;
+    };
+
+    match *event {
+      Event::MouseButtonDown {
+        which,
+        mouse_btn: MouseButton::Left,
+        ..
+      } if which != TOUCH_MOUSE_ID => self.press(PointerSource::Mouse, position),
+      Event::MouseMotion { which, .. } if which != TOUCH_MOUSE_ID => {
+        self.move_to(context, PointerSource::Mouse, position)
+      },
+      Event::MouseButtonUp {
+        which,
+        mouse_btn: MouseButton::Left,
+        ..
+      } if which != TOUCH_MOUSE_ID => self.release(context, PointerSource::Mouse, position),
+      Event::FingerDown { finger_id, .. } => self.press(PointerSource::Finger(finger_id), position),
+      Event::FingerMotion { finger_id, .. } => self.move_to(context, PointerSource::Finger(finger_id), position),
+      Event::FingerUp { finger_id, .. } => self.release(context, PointerSource::Finger(finger_id), position),
+      _ => {},
+    }
+  }
+
+  fn tick(&mut self, context: &mut Context, dt: f32) {
+    self.time += dt;
+
+    let Some(pointer) = &mut self.pointer else {
+      return;
+    };
+
+    pointer.velocity.forget_samples(self.time);
+
+    if pointer.is_dragging
+      || pointer.is_long_pressed
+      || self.time - pointer.start_time < self.props.thresholds.long_press_duration
+    {
+      return;
+    }
+
+    pointer.is_long_pressed = true;
+    let position = pointer.position;
+
+    if let Some(on_long_press) = &mut self.props.on_long_press {
+      on_long_press(context, position);
+    }
+  }
+
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.props.child.as_ref().map(|child| child.into())
+  }
+}
@@ -1,11 +1,35 @@
+pub mod animated;
+pub mod animated_shader;
+pub mod animated_sprite;
 pub mod app;
+pub mod children;
+pub mod clip;
+pub mod flex;
+pub mod focus;
+pub mod focus_scope;
+pub mod gesture_detector;
 pub mod multi_child_layout;
+pub mod scroll_view;
 pub mod shake;
+pub mod stack;
 pub mod stateful_layout;
 pub mod stateless_layout;
+pub mod transform;
 
+pub use animated::Animated;
+pub use animated_shader::AnimatedShader;
+pub use animated_sprite::AnimatedSprite;
 pub use app::App;
+pub use children::Children;
+pub use clip::{Clip, ClipShape};
+pub use flex::{CrossAxisAlignment, Flex, FlexChild, MainAxisAlignment};
+pub use focus::Focus;
+pub use focus_scope::FocusScope;
+pub use gesture_detector::{Drag, GestureDetector, GestureThresholds};
 pub use multi_child_layout::MultiChildLayout;
+pub use scroll_view::{ScrollBar, ScrollView};
 pub use shake::Shake;
+pub use stack::{Stack, StackChild};
 pub use stateful_layout::StatefulLayout;
 pub use stateless_layout::StatelessLayout;
+pub use transform::Transform;
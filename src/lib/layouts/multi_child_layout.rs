@@ -1,15 +1,55 @@
-use crate::{common::Sharable, models::Box2D, Context, View};
+use super::Children;
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints, PointerEvent},
+  Context, View,
+};
 use sdl2::event::Event;
 use skia_safe::Canvas;
 
 pub trait MultiChildLayout {
+  // Called with each event before it is dispatched to the children, while on_event() is called after that
+  fn on_capture_event(&mut self, _context: &mut Context, _event: &Event) {}
+
   fn on_event(&mut self, _context: &mut Context, _event: &Event) {}
+  fn on_pointer_event(&mut self, _context: &mut Context, _event: &PointerEvent) {}
   fn tick(&mut self, _context: &mut Context, _dt: f32) {}
 
+  fn get_size(&self) -> (f32, f32) {
+    (f32::MAX, f32::MAX)
+  }
+
+  fn measure(&self, constraints: Constraints, _children: &mut Children) -> (f32, f32) {
+    constraints.constrain(self.get_size())
+  }
+
   fn calc_rect_left(&self, constraint: Box2D, _child: &View) -> Box2D {
     constraint
   }
 
+  // Returns the rect of each child in the given rect of this layout
+  fn arrange(&self, rect: Box2D, children: &mut Children) -> Vec<Box2D> {
+    let mut child_rect = rect;
+
+    (0..children.len())
+      .map(|index| {
+        let rect = child_rect;
+
+        // Tell the layout to reduce the constraint for the next child
+        child_rect = children.with_view(index, |child| self.calc_rect_left(child_rect, child));
+
+        rect
+      })
+      .collect()
+  }
+
+  // Children with a higher z-index are drawn on top and hit tested first, while they keep their index otherwise so that
+  // their state does not move to another child as the z-index changes. Returns the z-index of each child made in order,
+  // and children left out have a z-index of 0.
+  fn get_z_indices(&self) -> Vec<i32> {
+    vec![]
+  }
+
   fn pre_draw(&self, _canvas: &Canvas, _constraint: Box2D) {}
 
   fn make(&self, _constraint: Box2D) -> Vec<Sharable<View>> {
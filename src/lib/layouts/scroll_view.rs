@@ -0,0 +1,343 @@
+use super::{clip::ClipShape, stateful_layout::State, Children, StatefulLayout};
+use crate::{
+  common::{Sharable, VelocityTracker},
+  models::{Axis, Box2D, Constraints},
+  Context, View,
+};
+use sdl2::{
+  event::Event,
+  keyboard::Keycode,
+  mouse::{MouseButton, MouseWheelDirection},
+};
+use skia_safe::{Canvas, Color, Matrix, Paint, RRect, Rect};
+use std::{
+  cell::{Cell, RefCell},
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Below this speed in pixels per second, scrolling by inertia stops
+const MIN_VELOCITY: f32 = 1f32;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScrollBar {
+  pub thickness: f32,
+  pub color: Color,
+}
+
+impl Default for ScrollBar {
+  fn default() -> Self {
+    Self {
+      thickness: 4f32,
+      color: Color::from_argb(128, 128, 128, 128),
+    }
+  }
+}
+
+// Shows the part of its child inside its rect, which can be larger than its rect along the given axis. The child is
+// scrolled by the mouse wheel, dragging with the left mouse button and the arrow, page up, page down, home and end
+// keys. After dragging, it keeps scrolling by inertia and slows down by the given friction.
+pub struct ScrollView {
+  pub axis: Axis,
+
+  // Distance in pixels scrolled for each mouse wheel notch or arrow key press
+  pub step: f32,
+
+  // The fraction of the scrolling velocity lost per second is 1 - e^(-friction)
+  pub friction: f32,
+
+  pub scroll_bar: Option<ScrollBar>,
+
+  // Distance in pixels scrolled from the start of the child. The parent can share it to read or set the scroll
+  // position, which is clamped to the scrollable range.
+  pub offset: Rc<Cell<f32>>,
+
+  pub child: Option<Sharable<View>>,
+}
+
+impl Default for ScrollView {
+  fn default() -> Self {
+    Self {
+      axis: Axis::Vertical,
+      step: 40f32,
+      friction: 4f32,
+      scroll_bar: Some(ScrollBar::default()),
+      offset: Rc::new(Cell::new(0f32)),
+      child: None,
+    }
+  }
+}
+
+impl Debug for ScrollView {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("ScrollView")
+      .field("axis", &self.axis)
+      .field("step", &self.step)
+      .field("friction", &self.friction)
+      .field("scroll_bar", &self.scroll_bar)
+      .field("offset", &self.offset)
+      .finish_non_exhaustive()
+  }
+}
+
+impl StatefulLayout for ScrollView {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    // Preconditions
+    assert!(self.step >= 0f32, "step must be a non-negative value");
+    assert!(self.friction >= 0f32, "friction must be a non-negative value");
+
+    Rc::new(RefCell::new(ScrollViewState {
+      axis: self.axis,
+      step: self.step,
+      friction: self.friction,
+      scroll_bar: self.scroll_bar,
+      offset: Rc::clone(&self.offset),
+      velocity: 0f32,
+      time: 0f32,
+      drag: None,
+      viewport: Cell::new(Box2D::default()),
+      content_size: Cell::new((0f32, 0f32)),
+      child: self.child.take().map(Sharable::into_shared),
+    }))
+  }
+}
+
+struct ScrollViewState {
+  axis: Axis,
+  step: f32,
+  friction: f32,
+  scroll_bar: Option<ScrollBar>,
+  offset: Rc<Cell<f32>>,
+
+  // Pixels per second along the axis, which is only used for inertia after dragging
+  velocity: f32,
+
+  // Seconds ticked since this state is made
+  time: f32,
+
+  // Tracks the pointer while dragging, which resolves the velocity when it is released
+  drag: Option<VelocityTracker>,
+
+  // Resolved by the last layout pass
+  viewport: Cell<Box2D>,
+  content_size: Cell<(f32, f32)>,
+
+  child: Option<Rc<RefCell<View>>>,
+}
+
+impl Debug for ScrollViewState {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("ScrollViewState")
+      .field("axis", &self.axis)
+      .field("offset", &self.offset)
+      .field("velocity", &self.velocity)
+      .field("time", &self.time)
+      .field("drag", &self.drag)
+      .field("viewport", &self.viewport)
+      .field("content_size", &self.content_size)
+      .finish_non_exhaustive()
+  }
+}
+
+impl ScrollViewState {
+  fn get_max_offset(&self) -> f32 {
+    (self.axis.get_main(self.content_size.get()) - self.axis.get_main(self.viewport.get().size)).max(0f32)
+  }
+
+  fn get_offset(&self) -> f32 {
+    self.offset.get().clamp(0f32, self.get_max_offset())
+  }
+
+  fn scroll_to(&mut self, offset: f32) {
+    let max_offset = self.get_max_offset();
+
+    // Stop scrolling by inertia at both ends
+    if !(0f32..=max_offset).contains(&offset) {
+      self.velocity = 0f32;
+    }
+
+    self.offset.set(offset.clamp(0f32, max_offset));
+  }
+
+  fn scroll_by(&mut self, distance: f32) {
+    self.scroll_to(self.get_offset() + distance);
+  }
+
+  // The child is unbounded along the axis so that it can be larger than this layout
+  fn get_child_constraints(&self, constraints: Constraints) -> Constraints {
+    Constraints {
+      min: self.axis.pack(0f32, self.axis.get_cross(constraints.min)),
+      max: self.axis.pack(f32::MAX, self.axis.get_cross(constraints.max)),
+    }
+  }
+}
+
+impl State for ScrollViewState {
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    let page = self.axis.get_main(self.viewport.get().size);
+
+    match *event {
+      Event::MouseWheel { x, y, direction, .. } => {
+        let notches = match direction {
+          MouseWheelDirection::Flipped => -self.axis.get_main((x as _, y as _)),
+          _ => self.axis.get_main((x as _, y as _)),
+        };
+
+        // Scrolling the wheel up moves the child down, which reveals its start
+        let offset = self.get_offset();
+        self.velocity = 0f32;
+        self.scroll_by(-notches * self.step);
+
+        // Let an outer scroll view scroll only when this one has reached its end
+        if self.get_offset() != offset {
+          context.stop_propagation();
+        }
+      },
+      Event::MouseButtonDown {
+        mouse_btn: MouseButton::Left,
+        x,
+        y,
+        ..
+      } if self.viewport.get().contains((x as _, y as _)) => {
+        self.velocity = 0f32;
+        self.drag = Some(VelocityTracker::new(self.time, (x as _, y as _)));
+      },
+      Event::MouseMotion { x, y, xrel, yrel, .. } => {
+        if let Some(drag) = &mut self.drag {
+          drag.add_sample(self.time, (x as _, y as _));
+          self.scroll_by(-self.axis.get_main((xrel as _, yrel as _)));
+        }
+      },
+      Event::MouseButtonUp {
+        mouse_btn: MouseButton::Left,
+        ..
+      } => {
+        if let Some(drag) = self.drag.take() {
+          // Moving the pointer down moves the child down, which scrolls back to its start
+          self.velocity = -self.axis.get_main(drag.get_velocity(self.time));
+        }
+      },
+      Event::KeyDown {
+        keycode: Some(keycode), ..
+      } => {
+        let offset = self.get_offset();
+
+        match (self.axis, keycode) {
+          (Axis::Vertical, Keycode::Up) | (Axis::Horizontal, Keycode::Left) => self.scroll_by(-self.step),
+          (Axis::Vertical, Keycode::Down) | (Axis::Horizontal, Keycode::Right) => self.scroll_by(self.step),
+          (_, Keycode::PageUp) => self.scroll_by(-page),
+          (_, Keycode::PageDown) => self.scroll_by(page),
+          (_, Keycode::Home) => self.scroll_to(0f32),
+          (_, Keycode::End) => self.scroll_to(self.get_max_offset()),
+          _ => {},
+        }
+
+        // Arrow keys move focus only when this scroll view has reached its end
+        if self.get_offset() != offset {
+          context.stop_propagation();
+        }
+      },
+      _ => {},
+    }
+  }
+
+  fn tick(&mut self, _context: &mut Context, dt: f32) {
+    self.time += dt;
+
+    if let Some(drag) = &mut self.drag {
+      drag.forget_samples(self.time);
+      return;
+    }
+
+    if self.velocity.abs() < MIN_VELOCITY {
+      self.velocity = 0f32;
+      return;
+    }
+
+    self.scroll_by(self.velocity * dt);
+    self.velocity *= (-self.friction * dt).exp();
+  }
+
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    let content_size = if child.is_empty() {
+      (0f32, 0f32)
+    } else {
+      child.measure(0, self.get_child_constraints(constraints))
+    };
+
+    // Take all the space given along the axis and fit the child across it
+    constraints.constrain(self.axis.pack(f32::MAX, self.axis.get_cross(content_size)))
+  }
+
+  fn arrange(&self, rect: Box2D, child: &mut Children) -> Box2D {
+    let content_size = if child.is_empty() {
+      (0f32, 0f32)
+    } else {
+      child.measure(0, self.get_child_constraints(rect.into()))
+    };
+
+    self.viewport.set(rect);
+    self.content_size.set(content_size);
+
+    Box2D {
+      position: rect.position,
+      size: content_size,
+    }
+  }
+
+  fn get_transform(&self, _rect: Box2D) -> Option<Matrix> {
+    Some(Matrix::translate(self.axis.pack(-self.get_offset(), 0f32)))
+  }
+
+  fn is_clipping(&self) -> bool {
+    true
+  }
+
+  fn pre_draw(&self, canvas: &Canvas, constraint: Box2D) {
+    canvas.save();
+    ClipShape::Rect.clip(canvas, constraint, false);
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.child.as_ref().map(|child| child.into())
+  }
+
+  fn post_draw(&self, canvas: &Canvas, constraint: Box2D) {
+    canvas.restore();
+
+    let Some(scroll_bar) = self.scroll_bar else {
+      return;
+    };
+
+    let max_offset = self.get_max_offset();
+
+    // Nothing to scroll
+    if max_offset == 0f32 {
+      return;
+    }
+
+    // The thumb is as long relative to the track as the visible part relative to the whole child
+    let track = self.axis.get_main(constraint.size);
+    let thumb = (track * track / self.axis.get_main(self.content_size.get())).max(scroll_bar.thickness * 2f32);
+    let (x, y) = self.axis.pack(
+      (track - thumb) * self.get_offset() / max_offset,
+      self.axis.get_cross(constraint.size) - scroll_bar.thickness,
+    );
+    let (width, height) = self.axis.pack(thumb, scroll_bar.thickness);
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_color(scroll_bar.color);
+
+    canvas.draw_rrect(
+      RRect::new_rect_xy(
+        Rect::from_xywh(constraint.position.0 + x, constraint.position.1 + y, width, height),
+        scroll_bar.thickness / 2f32,
+        scroll_bar.thickness / 2f32,
+      ),
+      &paint,
+    );
+  }
+}
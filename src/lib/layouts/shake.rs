@@ -4,14 +4,13 @@ use crate::{
   models::Box2D,
   Context, View,
 };
-use skia_safe::Canvas;
+use skia_safe::Matrix;
 use std::{
   cell::{Cell, RefCell},
   fmt::{self, Debug, Formatter},
   rc::Rc,
 };
 use tinyrand::Rand;
-use tinyrand_std::thread_rand;
 
 #[derive(Default)]
 pub struct Shake {
@@ -29,11 +28,7 @@ impl Debug for Shake {
 }
 
 impl StatefulLayout for Shake {
-  fn get_key(&self) -> &str {
-    "lib/layouts/shake"
-  }
-
-  fn make_state(&mut self) -> Rc<RefCell<dyn State>> {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
     Rc::new(RefCell::new(ShakeState::new(
       Rc::clone(&self.is_enabled),
       self.child.take(),
@@ -68,38 +63,32 @@ impl ShakeState {
       angle: 0f32,
       clock: Clock::new(0.02f32),
       is_enabled,
-      child: match child {
-        Some(Sharable::Owned(child)) => Some(Rc::new(RefCell::new(child))),
-        Some(Sharable::Shared(child)) => Some(child),
-        None => None,
-      },
+      child: child.map(Sharable::into_shared),
     }
   }
 }
 
 impl State for ShakeState {
-  fn tick(&mut self, _context: &mut Context, dt: f32) {
+  fn tick(&mut self, context: &mut Context, dt: f32) {
     if self.is_enabled.get() {
       self.clock.advance(dt, |_| {
-        self.angle = (thread_rand().next_u32() as f32 / u32::MAX as f32) * 2f32 * std::f32::consts::PI;
+        self.angle = (context.get_rng().next_u32() as f32 / u32::MAX as f32) * 2f32 * std::f32::consts::PI;
       });
     }
   }
 
-  fn pre_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+  fn get_transform(&self, _rect: Box2D) -> Option<Matrix> {
     if self.is_enabled.get() {
-      canvas.save();
-      canvas.translate((self.strength * self.angle.cos(), self.strength * self.angle.sin()));
+      Some(Matrix::translate((
+        self.strength * self.angle.cos(),
+        self.strength * self.angle.sin(),
+      )))
+    } else {
+      None
     }
   }
 
   fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
     self.child.as_ref().map(|child| child.into())
   }
-
-  fn post_draw(&self, canvas: &Canvas, _constraint: Box2D) {
-    if self.is_enabled.get() {
-      canvas.restore();
-    }
-  }
 }
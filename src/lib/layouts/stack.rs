@@ -0,0 +1,128 @@
+use super::{Children, MultiChildLayout};
+use crate::{
+  common::Sharable,
+  models::{Alignment, Box2D, Constraints, Insets, Overflow},
+  View,
+};
+use skia_safe::{Canvas, Rect};
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// A child of a stack, which is placed by its alignment in the rect of the stack deflated by its insets
+#[derive(Default)]
+pub struct StackChild {
+  pub alignment: Alignment,
+  pub insets: Insets,
+
+  // Children with a higher z-index are drawn on top. Children with the same z-index keep their order in the stack.
+  pub z_index: i32,
+
+  pub child: Option<Rc<RefCell<View>>>,
+}
+
+impl Debug for StackChild {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("StackChild")
+      .field("alignment", &self.alignment)
+      .field("insets", &self.insets)
+      .field("z_index", &self.z_index)
+      .finish_non_exhaustive()
+  }
+}
+
+impl StackChild {
+  pub fn new(child: Option<Sharable<View>>) -> Self {
+    Self {
+      child: child.map(Sharable::into_shared),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<Option<Sharable<View>>> for StackChild {
+  fn from(child: Option<Sharable<View>>) -> Self {
+    Self::new(child)
+  }
+}
+
+// Children share the rect of the stack and are drawn on top of each other
+#[derive(Debug, Default)]
+pub struct Stack {
+  pub overflow: Overflow,
+  pub children: Vec<StackChild>,
+}
+
+impl Stack {
+  // Children without a view take no slot, so that slots follow the order of the children given
+  fn get_children(&self) -> impl Iterator<Item = &StackChild> {
+    self.children.iter().filter(|child| child.child.is_some())
+  }
+}
+
+impl MultiChildLayout for Stack {
+  // Wraps the largest child together with its insets
+  fn measure(&self, constraints: Constraints, children: &mut Children) -> (f32, f32) {
+    let size = self
+      .get_children()
+      .enumerate()
+      .fold((0f32, 0f32), |size, (index, child)| {
+        let insets = child.insets.get_size();
+        let child_size = children.measure(index, constraints.loosen().deflate(insets));
+        (size.0.max(child_size.0 + insets.0), size.1.max(child_size.1 + insets.1))
+      });
+
+    constraints.constrain(size)
+  }
+
+  fn arrange(&self, rect: Box2D, children: &mut Children) -> Vec<Box2D> {
+    self
+      .get_children()
+      .enumerate()
+      .map(|(index, child)| {
+        let child_rect = child.insets.deflate(rect);
+        let size = children.measure(index, Constraints::loose(child_rect.size));
+        child.alignment.align(size, child_rect)
+      })
+      .collect()
+  }
+
+  fn get_z_indices(&self) -> Vec<i32> {
+    self.get_children().map(|child| child.z_index).collect()
+  }
+
+  fn pre_draw(&self, canvas: &Canvas, constraint: Box2D) {
+    if self.overflow == Overflow::Clip {
+      canvas.save();
+
+      canvas.clip_rect(
+        Rect::from_xywh(
+          constraint.position.0,
+          constraint.position.1,
+          constraint.size.0,
+          constraint.size.1,
+        ),
+        None,
+        None,
+      );
+    }
+  }
+
+  fn make(&self, _constraint: Box2D) -> Vec<Sharable<View>> {
+    self
+      .children
+      .iter()
+      .filter_map(|child| child.child.as_ref())
+      .map(|child| child.into())
+      .collect()
+  }
+
+  fn post_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    if self.overflow == Overflow::Clip {
+      canvas.restore();
+    }
+  }
+}
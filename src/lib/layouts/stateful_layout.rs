@@ -1,21 +1,108 @@
-use crate::{common::Sharable, models::Box2D, Context, View};
+use super::Children;
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints, PointerEvent},
+  Context, View,
+};
 use sdl2::event::Event;
-use skia_safe::Canvas;
-use std::{cell::RefCell, rc::Rc};
+use skia_safe::{Canvas, Matrix};
+use std::{
+  any::{self, Any},
+  cell::RefCell,
+  rc::Rc,
+};
 
 pub trait StatefulLayout {
-  fn get_key(&self) -> &str;
-  fn make_state(&mut self) -> Rc<RefCell<dyn State>>;
+  // Only needed to keep the state of this layout when it is reordered among its siblings, otherwise the engine
+  // identifies this layout by its position in the view tree
+  fn get_key(&self) -> Option<&str> {
+    None
+  }
+
+  fn get_type_name(&self) -> &'static str {
+    any::type_name::<Self>()
+  }
+
+  fn make_state(&mut self, context: &mut Context) -> Rc<RefCell<dyn State>>;
+
+  // Called whenever the state of this layout already exists, which is the only way to pass props changed after
+  // make_state() to the state through State::on_update(). Return None once the props are taken, because this is
+  // called for each pass over the view tree.
+  fn take_props(&mut self) -> Option<Box<dyn Any>> {
+    None
+  }
+}
+
+// For take_props() of a layout with callbacks. Callbacks can capture anything from the parent, so they are replaced on
+// each make of the parent together with the child. A child not taken yet is a sign that these props are new.
+pub(super) fn take_child_with_props<P: Any>(has_child: bool, take: impl FnOnce() -> P) -> Option<Box<dyn Any>> {
+  if !has_child {
+    return None;
+  }
+
+  Some(Box::new(take()))
 }
 
 pub trait State {
+  // Called once right after this state is made for a layout newly added to the view tree
+  fn on_mount(&mut self, _context: &mut Context) {}
+
+  // Called once right before this state is dropped because its layout is no longer produced in the view tree, so
+  // release sounds, timers and shared cells here
+  fn on_unmount(&mut self, _context: &mut Context) {}
+
+  // Called with the props taken from a layout produced again for this state. Call context.mark_dirty() to remake the
+  // child with them.
+  fn on_update(&mut self, _context: &mut Context, _props: Box<dyn Any>) {}
+
+  // Called with each event before it is dispatched to the child, while on_event() is called after that
+  fn on_capture_event(&mut self, _context: &mut Context, _event: &Event) {}
+
   fn on_event(&mut self, _context: &mut Context, _event: &Event) {}
+  fn on_pointer_event(&mut self, _context: &mut Context, _event: &PointerEvent) {}
+
+  // Called at the end of the pass over the view tree in which this state gains or loses focus
+  fn on_focus_gained(&mut self, _context: &mut Context) {}
+  fn on_focus_lost(&mut self, _context: &mut Context) {}
+
   fn tick(&mut self, _context: &mut Context, _dt: f32) {}
 
+  // Only a focusable state is visited by focus traversal. Call context.request_focus() to focus it directly.
+  fn is_focusable(&self) -> bool {
+    false
+  }
+
+  // Focus traversal with Tab, Shift+Tab and arrow keys stays among the focusable descendants of a focus scope
+  fn is_focus_scope(&self) -> bool {
+    false
+  }
+
   fn get_size(&self) -> (f32, f32) {
     (f32::MAX, f32::MAX)
   }
 
+  fn measure(&self, constraints: Constraints, _child: &mut Children) -> (f32, f32) {
+    constraints.constrain(self.get_size())
+  }
+
+  // Returns the rect of the child in the given rect of this layout
+  fn arrange(&self, rect: Box2D, _child: &mut Children) -> Box2D {
+    rect
+  }
+
+  // The transform of the child in the given rect of this layout. The engine applies it to the canvas between
+  // pre_draw() and post_draw() and maps pointer positions of events back through it before dispatching them to the
+  // child.
+  fn get_transform(&self, _rect: Box2D) -> Option<Matrix> {
+    None
+  }
+
+  // Whether the child is clipped to the rect of this layout. A transformed child is otherwise hit tested where it is
+  // drawn, which can be outside the rect of this layout.
+  fn is_clipping(&self) -> bool {
+    false
+  }
+
   fn pre_draw(&self, _canvas: &Canvas, _constraint: Box2D) {}
 
   fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
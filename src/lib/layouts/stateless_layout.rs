@@ -1,15 +1,46 @@
-use crate::{common::Sharable, models::Box2D, Context, View};
+use super::Children;
+use crate::{
+  common::Sharable,
+  models::{Box2D, Constraints, PointerEvent},
+  Context, View,
+};
 use sdl2::event::Event;
-use skia_safe::Canvas;
+use skia_safe::{Canvas, Matrix};
 
 pub trait StatelessLayout {
+  // Called with each event before it is dispatched to the child, while on_event() is called after that
+  fn on_capture_event(&mut self, _context: &Context, _event: &Event) {}
+
   fn on_event(&mut self, _context: &Context, _event: &Event) {}
+  fn on_pointer_event(&mut self, _context: &Context, _event: &PointerEvent) {}
   fn tick(&mut self, _context: &Context, _dt: f32) {}
 
   fn get_size(&self) -> (f32, f32) {
     (f32::MAX, f32::MAX)
   }
 
+  fn measure(&self, constraints: Constraints, _child: &mut Children) -> (f32, f32) {
+    constraints.constrain(self.get_size())
+  }
+
+  // Returns the rect of the child in the given rect of this layout
+  fn arrange(&self, rect: Box2D, _child: &mut Children) -> Box2D {
+    rect
+  }
+
+  // The transform of the child in the given rect of this layout. The engine applies it to the canvas between
+  // pre_draw() and post_draw() and maps pointer positions of events back through it before dispatching them to the
+  // child.
+  fn get_transform(&self, _rect: Box2D) -> Option<Matrix> {
+    None
+  }
+
+  // Whether the child is clipped to the rect of this layout. A transformed child is otherwise hit tested where it is
+  // drawn, which can be outside the rect of this layout.
+  fn is_clipping(&self) -> bool {
+    false
+  }
+
   fn pre_draw(&self, _canvas: &Canvas, _constraint: Box2D) {}
 
   fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
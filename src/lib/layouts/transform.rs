@@ -0,0 +1,118 @@
+use super::{Children, StatelessLayout};
+use crate::{
+  common::Sharable,
+  models::{Alignment, Box2D, Constraints},
+  View,
+};
+use skia_safe::{Canvas, Matrix};
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Transforms its child when drawing without affecting the layout. Pointer positions of events are mapped back so
+// that the child receives them in the space it is drawn in.
+pub struct Transform {
+  // The pivot of rotation, scale and skew in the rect of this layout
+  pub origin: Alignment,
+
+  pub translation: (f32, f32),
+
+  // Clockwise in degrees
+  pub rotation: f32,
+
+  pub scale: (f32, f32),
+  pub skew: (f32, f32),
+
+  // Between 0 and 1. The child is drawn into a layer which is blended with this opacity if less than 1.
+  pub opacity: f32,
+
+  pub child: Option<Rc<RefCell<View>>>,
+}
+
+impl Default for Transform {
+  fn default() -> Self {
+    Self {
+      origin: Alignment::CENTER,
+      translation: (0f32, 0f32),
+      rotation: 0f32,
+      scale: (1f32, 1f32),
+      skew: (0f32, 0f32),
+      opacity: 1f32,
+      child: None,
+    }
+  }
+}
+
+impl Debug for Transform {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Transform")
+      .field("origin", &self.origin)
+      .field("translation", &self.translation)
+      .field("rotation", &self.rotation)
+      .field("scale", &self.scale)
+      .field("skew", &self.skew)
+      .field("opacity", &self.opacity)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Transform {
+  pub fn new(child: Option<Sharable<View>>) -> Self {
+    Self {
+      child: child.map(Sharable::into_shared),
+      ..Default::default()
+    }
+  }
+
+  fn is_translucent(&self) -> bool {
+    // Preconditions
+    assert!((0f32..=1f32).contains(&self.opacity), "opacity must be between 0 and 1");
+
+    self.opacity < 1f32
+  }
+}
+
+impl StatelessLayout for Transform {
+  // Takes the size of the child as if it is not transformed
+  fn measure(&self, constraints: Constraints, child: &mut Children) -> (f32, f32) {
+    child.measure_single(constraints)
+  }
+
+  fn get_transform(&self, rect: Box2D) -> Option<Matrix> {
+    let pivot = (
+      rect.position.0 + rect.size.0 * self.origin.x,
+      rect.position.1 + rect.size.1 * self.origin.y,
+    );
+
+    let mut transform = Matrix::translate(self.translation);
+
+    transform
+      .pre_translate(pivot)
+      .pre_rotate(self.rotation, None)
+      .pre_skew(self.skew, None)
+      .pre_scale(self.scale, None)
+      .pre_translate((-pivot.0, -pivot.1));
+
+    Some(transform)
+  }
+
+  fn pre_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    // The layer is unbounded because the transformed child can be drawn outside the rect of this layout
+    if self.is_translucent() {
+      canvas.save_layer_alpha(None, (self.opacity * 255f32).round() as _);
+    }
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.child.as_ref().map(|child| child.into())
+  }
+
+  fn post_draw(&self, canvas: &Canvas, _constraint: Box2D) {
+    if self.is_translucent() {
+      canvas.restore();
+    }
+  }
+}
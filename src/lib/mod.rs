@@ -1,9 +1,12 @@
+pub mod animations;
 pub mod common;
 pub mod context;
 pub(crate) mod engine;
+pub mod input;
 pub mod layouts;
 pub mod models;
 pub mod nodes;
+pub mod testing;
 pub mod view;
 
 pub use context::Context;
@@ -0,0 +1,31 @@
+use super::Box2D;
+
+// Where a view is placed in a larger rect, where (0, 0) is the top left corner and (1, 1) is the bottom right corner
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Alignment {
+  pub x: f32,
+  pub y: f32,
+}
+
+impl Alignment {
+  pub const TOP_LEFT: Alignment = Alignment { x: 0f32, y: 0f32 };
+  pub const TOP: Alignment = Alignment { x: 0.5f32, y: 0f32 };
+  pub const TOP_RIGHT: Alignment = Alignment { x: 1f32, y: 0f32 };
+  pub const LEFT: Alignment = Alignment { x: 0f32, y: 0.5f32 };
+  pub const CENTER: Alignment = Alignment { x: 0.5f32, y: 0.5f32 };
+  pub const RIGHT: Alignment = Alignment { x: 1f32, y: 0.5f32 };
+  pub const BOTTOM_LEFT: Alignment = Alignment { x: 0f32, y: 1f32 };
+  pub const BOTTOM: Alignment = Alignment { x: 0.5f32, y: 1f32 };
+  pub const BOTTOM_RIGHT: Alignment = Alignment { x: 1f32, y: 1f32 };
+
+  // Returns the rect of the given size placed in the given rect
+  pub fn align(self, size: (f32, f32), rect: Box2D) -> Box2D {
+    Box2D {
+      position: (
+        rect.position.0 + (rect.size.0 - size.0) * self.x,
+        rect.position.1 + (rect.size.1 - size.1) * self.y,
+      ),
+      size,
+    }
+  }
+}
@@ -0,0 +1,32 @@
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Axis {
+  #[default]
+  Horizontal,
+  Vertical,
+}
+
+impl Axis {
+  // Returns the component of the given size or position which lies along this axis
+  pub const fn get_main(self, value: (f32, f32)) -> f32 {
+    match self {
+      Axis::Horizontal => value.0,
+      Axis::Vertical => value.1,
+    }
+  }
+
+  // Returns the component of the given size or position which lies across this axis
+  pub const fn get_cross(self, value: (f32, f32)) -> f32 {
+    match self {
+      Axis::Horizontal => value.1,
+      Axis::Vertical => value.0,
+    }
+  }
+
+  // Inverse of get_main() and get_cross()
+  pub const fn pack(self, main: f32, cross: f32) -> (f32, f32) {
+    match self {
+      Axis::Horizontal => (main, cross),
+      Axis::Vertical => (cross, main),
+    }
+  }
+}
@@ -0,0 +1,12 @@
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Backend {
+  // Draw into an OpenGL window using the GPU
+  #[default]
+  Gl,
+
+  // Draw into an offscreen CPU raster surface without opening any window. The app quits after the given number of
+  // frames, or runs until it is interrupted if None.
+  Raster {
+    frame_count: Option<usize>,
+  },
+}
@@ -3,3 +3,19 @@ pub struct Box2D {
   pub position: (f32, f32),
   pub size: (f32, f32),
 }
+
+impl Box2D {
+  // Whether the given point lies in this box, including its top and left edges but excluding its bottom and right
+  // edges so that adjacent boxes never share a point
+  pub fn contains(self, point: (f32, f32)) -> bool {
+    (self.position.0..self.position.0 + self.size.0).contains(&point.0)
+      && (self.position.1..self.position.1 + self.size.1).contains(&point.1)
+  }
+
+  pub fn get_center(self) -> (f32, f32) {
+    (
+      self.position.0 + self.size.0 * 0.5f32,
+      self.position.1 + self.size.1 * 0.5f32,
+    )
+  }
+}
@@ -0,0 +1,60 @@
+use super::Box2D;
+
+// The minimum and maximum size a view can be when it is measured by its parent
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Constraints {
+  pub min: (f32, f32),
+  pub max: (f32, f32),
+}
+
+impl Constraints {
+  pub const UNBOUNDED: Constraints = Constraints::loose((f32::MAX, f32::MAX));
+
+  pub const fn tight(size: (f32, f32)) -> Self {
+    Self { min: size, max: size }
+  }
+
+  pub const fn loose(size: (f32, f32)) -> Self {
+    Self {
+      min: (0f32, 0f32),
+      max: size,
+    }
+  }
+
+  pub fn is_tight(self) -> bool {
+    self.min == self.max
+  }
+
+  pub fn loosen(self) -> Self {
+    Self::loose(self.max)
+  }
+
+  pub fn constrain(self, size: (f32, f32)) -> (f32, f32) {
+    (
+      size.0.clamp(self.min.0, self.max.0),
+      size.1.clamp(self.min.1, self.max.1),
+    )
+  }
+
+  // Shrinks both the minimum and maximum size by the given amount without going below zero
+  pub fn deflate(self, amount: (f32, f32)) -> Self {
+    Self {
+      min: ((self.min.0 - amount.0).max(0f32), (self.min.1 - amount.1).max(0f32)),
+      max: ((self.max.0 - amount.0).max(0f32), (self.max.1 - amount.1).max(0f32)),
+    }
+  }
+
+  // The box that a view measured with these constraints can at most occupy
+  pub const fn get_box(self) -> Box2D {
+    Box2D {
+      position: (0f32, 0f32),
+      size: self.max,
+    }
+  }
+}
+
+impl From<Box2D> for Constraints {
+  fn from(value: Box2D) -> Self {
+    Self::tight(value.size)
+  }
+}
@@ -0,0 +1,45 @@
+use super::Box2D;
+
+// Space to leave on each side of a rect
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Insets {
+  pub left: f32,
+  pub top: f32,
+  pub right: f32,
+  pub bottom: f32,
+}
+
+impl Insets {
+  pub const fn all(value: f32) -> Self {
+    Self {
+      left: value,
+      top: value,
+      right: value,
+      bottom: value,
+    }
+  }
+
+  pub const fn symmetric(horizontal: f32, vertical: f32) -> Self {
+    Self {
+      left: horizontal,
+      top: vertical,
+      right: horizontal,
+      bottom: vertical,
+    }
+  }
+
+  pub fn get_size(self) -> (f32, f32) {
+    (self.left + self.right, self.top + self.bottom)
+  }
+
+  // Shrinks the given rect by these insets without going below zero size
+  pub fn deflate(self, rect: Box2D) -> Box2D {
+    Box2D {
+      position: (rect.position.0 + self.left, rect.position.1 + self.top),
+      size: (
+        (rect.size.0 - self.left - self.right).max(0f32),
+        (rect.size.1 - self.top - self.bottom).max(0f32),
+      ),
+    }
+  }
+}
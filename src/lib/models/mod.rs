@@ -1,5 +1,25 @@
+pub mod alignment;
+pub mod axis;
+pub mod backend;
 pub mod box_2d;
+pub mod constraints;
 pub mod direction;
+pub mod insets;
+pub mod overflow;
+pub mod pointer_event;
+pub mod stroke;
+pub mod style;
+pub mod view_id;
 
+pub use alignment::Alignment;
+pub use axis::Axis;
+pub use backend::Backend;
 pub use box_2d::Box2D;
+pub use constraints::Constraints;
 pub use direction::Direction;
+pub use insets::Insets;
+pub use overflow::Overflow;
+pub use pointer_event::PointerEvent;
+pub use stroke::Stroke;
+pub use style::{Fill, Gradient, Shadow, Style};
+pub use view_id::ViewId;
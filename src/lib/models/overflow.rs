@@ -0,0 +1,7 @@
+// How a layout draws children which do not fit in its rect
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Overflow {
+  #[default]
+  Visible,
+  Clip,
+}
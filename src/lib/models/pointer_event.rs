@@ -0,0 +1,11 @@
+// Made by the engine from mouse motion for each view whose rect the pointer moves onto, over or off. Positions are in
+// the same space as the rect of the view.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PointerEvent {
+  Enter,
+
+  // Also made right after Enter
+  Hover { position: (f32, f32) },
+
+  Leave,
+}
@@ -0,0 +1,52 @@
+use super::{Box2D, Style};
+use skia_safe::{
+  paint::{self, Cap, Join},
+  Color, Paint, PathEffect,
+};
+
+// How the outline of a shape is drawn
+#[derive(Clone, Debug)]
+pub struct Stroke {
+  pub style: Style,
+  pub width: f32,
+  pub cap: Cap,
+  pub join: Join,
+
+  // Lengths of alternating on and off intervals along the outline. The outline is solid if empty.
+  pub dash: Vec<f32>,
+}
+
+impl Default for Stroke {
+  fn default() -> Self {
+    Self {
+      style: Color::WHITE.into(),
+      width: 1f32,
+      cap: Cap::default(),
+      join: Join::default(),
+      dash: vec![],
+    }
+  }
+}
+
+impl Stroke {
+  // Makes a paint for the outline of a shape drawn in the given rect
+  pub fn make_paint(&self, rect: Box2D) -> Paint {
+    // Preconditions
+    assert!(self.width >= 0f32, "width must be a non-negative number");
+    assert_eq!(self.dash.len() % 2, 0, "dash must have an even number of intervals");
+
+    let mut paint = self.style.make_paint(rect);
+
+    paint
+      .set_style(paint::Style::Stroke)
+      .set_stroke_width(self.width)
+      .set_stroke_cap(self.cap)
+      .set_stroke_join(self.join);
+
+    if !self.dash.is_empty() {
+      paint.set_path_effect(PathEffect::dash(&self.dash, 0f32));
+    }
+
+    paint
+  }
+}
@@ -0,0 +1,208 @@
+use super::Box2D;
+use crate::nodes::ImageSampling;
+use skia_safe::{image_filters, BlendMode, BlurStyle, Color, Image, MaskFilter, Matrix, Paint, Shader, TileMode};
+
+// Colors of a gradient at the given stops between 0 and 1. The colors are spread evenly if stops is empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Gradient {
+  pub colors: Vec<Color>,
+  pub stops: Vec<f32>,
+}
+
+impl Gradient {
+  fn get_stops(&self) -> Option<&[f32]> {
+    // Preconditions
+    assert!(self.colors.len() >= 2, "colors must have at least 2 colors");
+    assert!(
+      self.stops.is_empty() || self.stops.len() == self.colors.len(),
+      "stops must be empty or have as many stops as colors"
+    );
+
+    if self.stops.is_empty() {
+      None
+    } else {
+      Some(&self.stops)
+    }
+  }
+}
+
+// What fills a shape. Positions and radii are fractions of the rect being drawn so that the same fill can be used by
+// views of any size, e.g. (0, 0) is the top left corner and (1, 1) is the bottom right corner.
+#[derive(Clone, Debug)]
+pub enum Fill {
+  Color(Color),
+  LinearGradient {
+    from: (f32, f32),
+    to: (f32, f32),
+    gradient: Gradient,
+  },
+
+  // The radius is a fraction of the longer side of the rect
+  RadialGradient {
+    center: (f32, f32),
+    radius: f32,
+    gradient: Gradient,
+  },
+
+  SweepGradient {
+    center: (f32, f32),
+    gradient: Gradient,
+  },
+
+  // The image is repeated from the top left corner of the rect at its original size
+  Image {
+    image: Image,
+    tile_mode: TileMode,
+    sampling: ImageSampling,
+  },
+}
+
+// Nothing is drawn by default, same as the default color
+impl Default for Fill {
+  fn default() -> Self {
+    Fill::Color(Color::TRANSPARENT)
+  }
+}
+
+impl Fill {
+  fn make_shader(&self, rect: Box2D) -> Option<Shader> {
+    let to_point = |(x, y): (f32, f32)| (rect.position.0 + rect.size.0 * x, rect.position.1 + rect.size.1 * y);
+
+    match self {
+      Fill::Color(_) => None,
+      Fill::LinearGradient { from, to, gradient } => Shader::linear_gradient(
+        (to_point(*from), to_point(*to)),
+        gradient.colors.as_slice(),
+        gradient.get_stops(),
+        TileMode::Clamp,
+        None,
+        None,
+      ),
+      Fill::RadialGradient {
+        center,
+        radius,
+        gradient,
+      } => Shader::radial_gradient(
+        to_point(*center),
+        rect.size.0.max(rect.size.1) * radius,
+        gradient.colors.as_slice(),
+        gradient.get_stops(),
+        TileMode::Clamp,
+        None,
+        None,
+      ),
+      Fill::SweepGradient { center, gradient } => Shader::sweep_gradient(
+        to_point(*center),
+        gradient.colors.as_slice(),
+        gradient.get_stops(),
+        TileMode::Clamp,
+        None,
+        None,
+        None,
+      ),
+      Fill::Image {
+        image,
+        tile_mode,
+        sampling,
+      } => image.to_shader((*tile_mode, *tile_mode), *sampling, &Matrix::translate(rect.position)),
+    }
+  }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shadow {
+  pub offset: (f32, f32),
+  pub blur: f32,
+  pub color: Color,
+}
+
+impl Default for Shadow {
+  fn default() -> Self {
+    Self {
+      offset: (0f32, 4f32),
+      blur: 4f32,
+      color: Color::from_argb(128, 0, 0, 0),
+    }
+  }
+}
+
+// Describes how a shape is painted, which can be shared by many views
+#[derive(Clone, Debug)]
+pub struct Style {
+  pub fill: Fill,
+  pub blend_mode: BlendMode,
+
+  // Between 0 and 1, multiplied with the alpha of the fill
+  pub opacity: f32,
+
+  // Sigma of the gaussian blur applied to the shape. Useful for glows.
+  pub blur: Option<f32>,
+
+  pub shadow: Option<Shadow>,
+}
+
+impl Default for Style {
+  fn default() -> Self {
+    Self {
+      fill: Fill::default(),
+      blend_mode: BlendMode::SrcOver,
+      opacity: 1f32,
+      blur: None,
+      shadow: None,
+    }
+  }
+}
+
+impl From<Color> for Style {
+  fn from(color: Color) -> Self {
+    Self {
+      fill: Fill::Color(color),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<Fill> for Style {
+  fn from(fill: Fill) -> Self {
+    Self {
+      fill,
+      ..Default::default()
+    }
+  }
+}
+
+impl Style {
+  // Makes a paint for a shape drawn in the given rect
+  pub fn make_paint(&self, rect: Box2D) -> Paint {
+    // Preconditions
+    assert!((0f32..=1f32).contains(&self.opacity), "opacity must be between 0 and 1");
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true).set_blend_mode(self.blend_mode);
+
+    if let Fill::Color(color) = self.fill {
+      paint.set_color(color);
+    } else {
+      paint.set_shader(self.fill.make_shader(rect));
+    }
+
+    paint.set_alpha_f(paint.alpha_f() * self.opacity);
+
+    if let Some(sigma) = self.blur {
+      paint.set_mask_filter(MaskFilter::blur(BlurStyle::Normal, sigma, None));
+    }
+
+    if let Some(shadow) = self.shadow {
+      paint.set_image_filter(image_filters::drop_shadow(
+        shadow.offset,
+        (shadow.blur, shadow.blur),
+        shadow.color,
+        None,
+        None,
+        None,
+      ));
+    }
+
+    paint
+  }
+}
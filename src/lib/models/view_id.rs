@@ -0,0 +1,20 @@
+use std::{
+  collections::hash_map::DefaultHasher,
+  hash::{Hash, Hasher},
+};
+
+// Identifies a view by its position in the view tree, which stays the same across frames as long as the tree keeps its
+// shape
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ViewId(u64);
+
+impl ViewId {
+  pub const ROOT: ViewId = ViewId(0);
+
+  pub fn make_child(self, key: impl Hash) -> Self {
+    let mut hasher = DefaultHasher::new();
+    self.0.hash(&mut hasher);
+    key.hash(&mut hasher);
+    Self(hasher.finish())
+  }
+}
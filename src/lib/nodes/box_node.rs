@@ -1,14 +1,20 @@
 use super::Node;
-use crate::models::Box2D;
-use skia_safe::{Canvas, Color, Paint, Rect};
+use crate::models::{Box2D, Style};
+use skia_safe::{Canvas, Rect};
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+// A rect which fills its constraint. A plain color can be given as `style: color.into()`.
+#[derive(Clone, Debug, Default)]
 pub struct BoxNode {
-  pub color: Color,
+  pub style: Style,
 }
 
 impl Node for BoxNode {
   fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let mut paint = self.style.make_paint(constraint);
+
+    // Keep the edges sharp so that boxes next to each other, e.g. cells of a grid, are tiled without seams
+    paint.set_anti_alias(false);
+
     canvas.draw_rect(
       Rect::from_xywh(
         constraint.position.0,
@@ -16,7 +22,7 @@ impl Node for BoxNode {
         constraint.size.0,
         constraint.size.1,
       ),
-      Paint::default().set_color(self.color),
+      &paint,
     );
   }
 }
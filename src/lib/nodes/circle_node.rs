@@ -0,0 +1,25 @@
+use super::{shape, Node};
+use crate::models::{Box2D, Stroke, Style};
+use skia_safe::Canvas;
+
+// The largest circle which fits in the center of its constraint
+#[derive(Clone, Debug, Default)]
+pub struct CircleNode {
+  pub fill: Option<Style>,
+  pub stroke: Option<Stroke>,
+}
+
+impl Node for CircleNode {
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let center = (
+      constraint.position.0 + constraint.size.0 * 0.5f32,
+      constraint.position.1 + constraint.size.1 * 0.5f32,
+    );
+
+    let radius = constraint.size.0.min(constraint.size.1) * 0.5f32;
+
+    shape::draw_shape(self.fill.as_ref(), self.stroke.as_ref(), constraint, |paint| {
+      canvas.draw_circle(center, radius, paint);
+    });
+  }
+}
@@ -33,12 +33,45 @@ impl Default for GridNode {
   }
 }
 
+impl GridNode {
+  fn get_cell_rect(&self, constraint: Box2D, i: usize) -> Box2D {
+    let size = (self.size.0.min(constraint.size.0), self.size.1.min(constraint.size.1));
+
+    let cell_size = (
+      (size.0 - self.gap.0 * (self.dim.0 - 1) as f32) / self.dim.0 as f32,
+      (size.1 - self.gap.1 * (self.dim.1 - 1) as f32) / self.dim.1 as f32,
+    );
+
+    let position = (
+      constraint.position.0 + (constraint.size.0 - size.0) * 0.5f32,
+      constraint.position.1 + (constraint.size.1 - size.1) * 1.0f32,
+    );
+
+    Box2D {
+      position: (
+        position.0 + (i % self.dim.0) as f32 * (cell_size.0 + self.gap.0),
+        position.1 + (i / self.dim.0) as f32 * (cell_size.1 + self.gap.1),
+      ),
+      size: cell_size,
+    }
+  }
+}
+
 impl Node for GridNode {
   fn on_event(&mut self, context: &mut Context, event: &Event) {
     // Preconditions
     debug_assert_ne!(self.dim.0, 0, "dim.0 must be a positive integer");
     debug_assert_ne!(self.dim.1, 0, "dim.1 must be a positive integer");
 
+    // Pointer events only reach the cell under the pointer
+    if let (Some(rect), Some(point)) = (context.get_rect(), context.get_pointer_position()) {
+      if let Some(i) = (0..self.dim.0 * self.dim.1).find(|&i| self.get_cell_rect(rect, i).contains(point)) {
+        (self.maker)(i).on_event(context, event);
+      }
+
+      return;
+    }
+
     for i in 0..self.dim.0 * self.dim.1 {
       (self.maker)(i).on_event(context, event);
     }
@@ -67,29 +100,8 @@ impl Node for GridNode {
     debug_assert!(self.size.0 > 0f32, "size.0 must be a positive value");
     debug_assert!(self.size.1 > 0f32, "size.1 must be a positive value");
 
-    let size = (self.size.0.min(constraint.size.0), self.size.1.min(constraint.size.1));
-
-    let cell_size = (
-      (size.0 - self.gap.0 * (self.dim.0 - 1) as f32) / self.dim.0 as f32,
-      (size.1 - self.gap.1 * (self.dim.1 - 1) as f32) / self.dim.1 as f32,
-    );
-
-    let position = (
-      constraint.position.0 + (constraint.size.0 - size.0) * 0.5f32,
-      constraint.position.1 + (constraint.size.1 - size.1) * 1.0f32,
-    );
-
     for i in 0..self.dim.0 * self.dim.1 {
-      (self.maker)(i).draw(
-        canvas,
-        Box2D {
-          position: (
-            position.0 + (i % self.dim.0) as f32 * (cell_size.0 + self.gap.0),
-            position.1 + (i / self.dim.0) as f32 * (cell_size.1 + self.gap.1),
-          ),
-          size: cell_size,
-        },
-      );
+      (self.maker)(i).draw(canvas, self.get_cell_rect(constraint, i));
     }
   }
 }
@@ -0,0 +1,188 @@
+use super::Node;
+use crate::models::{Alignment, Box2D, Constraints};
+use skia_safe::{
+  canvas::SrcRectConstraint, Canvas, CubicResampler, FilterMode, IRect, Image, MipmapMode, Paint, Rect, SamplingOptions,
+};
+use std::fmt::{self, Debug, Formatter};
+
+// How an image is scaled into the rect it is drawn in
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImageFit {
+  // Scale to the largest size that fits in the rect while keeping its aspect ratio
+  Contain,
+
+  // Scale to the smallest size that covers the rect while keeping its aspect ratio. The rest is cropped.
+  Cover,
+
+  // Stretch to the rect
+  #[default]
+  Fill,
+
+  // Keep its original size. The part outside of the rect is cropped.
+  None,
+}
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImageSampling {
+  // Keeps pixel art crisp
+  #[default]
+  Nearest,
+
+  Linear,
+  Cubic,
+}
+
+impl ImageSampling {
+  fn get_filter_mode(self) -> FilterMode {
+    match self {
+      ImageSampling::Nearest => FilterMode::Nearest,
+      ImageSampling::Linear | ImageSampling::Cubic => FilterMode::Linear,
+    }
+  }
+}
+
+impl From<ImageSampling> for SamplingOptions {
+  fn from(value: ImageSampling) -> Self {
+    match value {
+      ImageSampling::Nearest => SamplingOptions::new(FilterMode::Nearest, MipmapMode::None),
+      ImageSampling::Linear => SamplingOptions::new(FilterMode::Linear, MipmapMode::None),
+      ImageSampling::Cubic => CubicResampler::mitchell().into(),
+    }
+  }
+}
+
+#[derive(Clone)]
+pub struct ImageNode {
+  // Get one from Context::get_image(). Nothing is drawn if not given.
+  pub image: Option<Image>,
+
+  pub fit: ImageFit,
+
+  // Where the image is placed in the rect when it does not fill the rect
+  pub alignment: Alignment,
+
+  pub sampling: ImageSampling,
+
+  // The center rect of the image in pixels for nine-slice scaling. The corners keep their size, the edges are
+  // stretched along one axis and the center is stretched along both axes. Fit and alignment are ignored if given.
+  pub nine_slice: Option<IRect>,
+}
+
+impl Default for ImageNode {
+  fn default() -> Self {
+    Self {
+      image: None,
+      fit: ImageFit::default(),
+      alignment: Alignment::CENTER,
+      sampling: ImageSampling::default(),
+      nine_slice: None,
+    }
+  }
+}
+
+impl Debug for ImageNode {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("ImageNode")
+      .field("fit", &self.fit)
+      .field("alignment", &self.alignment)
+      .field("sampling", &self.sampling)
+      .field("nine_slice", &self.nine_slice)
+      .finish_non_exhaustive()
+  }
+}
+
+impl ImageNode {
+  // Returns the part of the image to draw and the rect to draw it in
+  fn calc_src_dst(&self, image_size: (f32, f32), rect: Box2D) -> (Box2D, Box2D) {
+    let image_box = Box2D {
+      position: (0f32, 0f32),
+      size: image_size,
+    };
+
+    let scale_x = rect.size.0 / image_size.0;
+    let scale_y = rect.size.1 / image_size.1;
+
+    match self.fit {
+      ImageFit::Contain => {
+        let scale = scale_x.min(scale_y);
+        let size = (image_size.0 * scale, image_size.1 * scale);
+        (image_box, self.alignment.align(size, rect))
+      },
+      ImageFit::Cover => {
+        let scale = scale_x.max(scale_y);
+        let src_size = (rect.size.0 / scale, rect.size.1 / scale);
+        (self.alignment.align(src_size, image_box), rect)
+      },
+      ImageFit::Fill => (image_box, rect),
+      ImageFit::None => {
+        let dst = self.alignment.align(image_size, rect);
+
+        // Crop both rects to the part of the image which lies in the rect
+        let left = (rect.position.0 - dst.position.0).max(0f32);
+        let top = (rect.position.1 - dst.position.1).max(0f32);
+        let size = (image_size.0.min(rect.size.0), image_size.1.min(rect.size.1));
+
+        (
+          Box2D {
+            position: (left, top),
+            size,
+          },
+          Box2D {
+            position: (dst.position.0 + left, dst.position.1 + top),
+            size,
+          },
+        )
+      },
+    }
+  }
+}
+
+impl Node for ImageNode {
+  // Keeps the aspect ratio of the image while shrinking it to fit in the constraints
+  fn measure(&self, constraints: Constraints) -> (f32, f32) {
+    let Some(image) = &self.image else {
+      return constraints.constrain((0f32, 0f32));
+    };
+
+    let (width, height) = (image.width() as f32, image.height() as f32);
+
+    if self.nine_slice.is_some() {
+      return constraints.constrain((width, height));
+    }
+
+    let scale = (constraints.max.0 / width).min(constraints.max.1 / height).min(1f32);
+    constraints.constrain((width * scale, height * scale))
+  }
+
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let Some(image) = &self.image else {
+      return;
+    };
+
+    let dst = Rect::from_xywh(
+      constraint.position.0,
+      constraint.position.1,
+      constraint.size.0,
+      constraint.size.1,
+    );
+
+    if let Some(center) = self.nine_slice {
+      canvas.draw_image_nine(image, center, dst, self.sampling.get_filter_mode(), None);
+      return;
+    }
+
+    let (src, dst) = self.calc_src_dst((image.width() as _, image.height() as _), constraint);
+
+    canvas.draw_image_rect_with_sampling_options(
+      image,
+      Some((
+        &Rect::from_xywh(src.position.0, src.position.1, src.size.0, src.size.1),
+        SrcRectConstraint::Fast,
+      )),
+      Rect::from_xywh(dst.position.0, dst.position.1, dst.size.0, dst.size.1),
+      self.sampling,
+      &Paint::default(),
+    );
+  }
+}
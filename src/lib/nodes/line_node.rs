@@ -0,0 +1,27 @@
+use super::Node;
+use crate::models::{Box2D, Stroke};
+use skia_safe::Canvas;
+
+// A line segment between two points relative to the top left corner of its constraint
+#[derive(Clone, Debug, Default)]
+pub struct LineNode {
+  pub from: (f32, f32),
+  pub to: (f32, f32),
+  pub stroke: Stroke,
+}
+
+impl Node for LineNode {
+  fn get_size(&self) -> (f32, f32) {
+    (self.from.0.max(self.to.0), self.from.1.max(self.to.1))
+  }
+
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let (x, y) = constraint.position;
+
+    canvas.draw_line(
+      (x + self.from.0, y + self.from.1),
+      (x + self.to.0, y + self.to.1),
+      &self.stroke.make_paint(constraint),
+    );
+  }
+}
@@ -1,7 +1,26 @@
 pub mod box_node;
+pub mod circle_node;
 pub mod grid_node;
+pub mod image_node;
+pub mod line_node;
 pub mod node;
+pub mod oval_node;
+pub mod path_node;
+pub mod rrect_node;
+pub mod shader_node;
+mod shape;
+pub mod sprite_node;
+pub mod text_node;
 
 pub use box_node::BoxNode;
+pub use circle_node::CircleNode;
 pub use grid_node::GridNode;
+pub use image_node::{ImageFit, ImageNode, ImageSampling};
+pub use line_node::LineNode;
 pub use node::Node;
+pub use oval_node::OvalNode;
+pub use path_node::PathNode;
+pub use rrect_node::RRectNode;
+pub use shader_node::{ShaderNode, Uniform};
+pub use sprite_node::SpriteNode;
+pub use text_node::{ParagraphCache, TextAlign, TextNode};
@@ -0,0 +1,25 @@
+use super::{shape, Node};
+use crate::models::{Box2D, Stroke, Style};
+use skia_safe::{Canvas, Rect};
+
+// An oval which touches all sides of its constraint
+#[derive(Clone, Debug, Default)]
+pub struct OvalNode {
+  pub fill: Option<Style>,
+  pub stroke: Option<Stroke>,
+}
+
+impl Node for OvalNode {
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let rect = Rect::from_xywh(
+      constraint.position.0,
+      constraint.position.1,
+      constraint.size.0,
+      constraint.size.1,
+    );
+
+    shape::draw_shape(self.fill.as_ref(), self.stroke.as_ref(), constraint, |paint| {
+      canvas.draw_oval(rect, paint);
+    });
+  }
+}
@@ -0,0 +1,26 @@
+use super::{shape, Node};
+use crate::models::{Box2D, Stroke, Style};
+use skia_safe::{Canvas, Matrix, Path};
+
+// An arbitrary path whose origin is placed at the top left corner of its constraint
+#[derive(Clone, Debug, Default)]
+pub struct PathNode {
+  pub path: Path,
+  pub fill: Option<Style>,
+  pub stroke: Option<Stroke>,
+}
+
+impl Node for PathNode {
+  fn get_size(&self) -> (f32, f32) {
+    let bounds = self.path.bounds();
+    (bounds.right.max(0f32), bounds.bottom.max(0f32))
+  }
+
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let path = self.path.with_transform(&Matrix::translate(constraint.position));
+
+    shape::draw_shape(self.fill.as_ref(), self.stroke.as_ref(), constraint, |paint| {
+      canvas.draw_path(&path, paint);
+    });
+  }
+}
@@ -0,0 +1,38 @@
+use super::{shape, Node};
+use crate::models::{Box2D, Stroke, Style};
+use skia_safe::{Canvas, RRect, Rect, Vector};
+
+// A rect with rounded corners which fills its constraint
+#[derive(Clone, Debug, Default)]
+pub struct RRectNode {
+  pub fill: Option<Style>,
+  pub stroke: Option<Stroke>,
+
+  // Radii of the top left, top right, bottom right and bottom left corners along x and y axes
+  pub radii: [(f32, f32); 4],
+}
+
+impl RRectNode {
+  pub fn with_radius(mut self, radius: f32) -> Self {
+    self.radii = [(radius, radius); 4];
+    self
+  }
+}
+
+impl Node for RRectNode {
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let rrect = RRect::new_rect_radii(
+      Rect::from_xywh(
+        constraint.position.0,
+        constraint.position.1,
+        constraint.size.0,
+        constraint.size.1,
+      ),
+      &self.radii.map(Vector::from),
+    );
+
+    shape::draw_shape(self.fill.as_ref(), self.stroke.as_ref(), constraint, |paint| {
+      canvas.draw_rrect(&rrect, paint);
+    });
+  }
+}
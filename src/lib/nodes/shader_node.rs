@@ -0,0 +1,136 @@
+use super::Node;
+use crate::models::Box2D;
+use skia_safe::{BlendMode, Canvas, Color, Color4f, Data, Matrix, Paint, Rect, RuntimeEffect};
+use std::fmt::{self, Debug, Formatter};
+
+// A value of a uniform declared in SkSL, e.g. `uniform float2 u_offset;` takes a Float2
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Uniform {
+  Float(f32),
+  Float2([f32; 2]),
+  Float3([f32; 3]),
+  Float4([f32; 4]),
+  Int(i32),
+
+  // Passed as a float4 in RGBA order with unpremultiplied components between 0 and 1
+  Color(Color),
+}
+
+impl Uniform {
+  fn to_bytes(self) -> Vec<u8> {
+    let floats = |values: &[f32]| values.iter().flat_map(|value| value.to_ne_bytes()).collect();
+
+    match self {
+      Uniform::Float(value) => floats(&[value]),
+      Uniform::Float2(values) => floats(&values),
+      Uniform::Float3(values) => floats(&values),
+      Uniform::Float4(values) => floats(&values),
+      Uniform::Int(value) => value.to_ne_bytes().to_vec(),
+      Uniform::Color(color) => {
+        let color = Color4f::from(color);
+        floats(&[color.r, color.g, color.b, color.a])
+      },
+    }
+  }
+}
+
+// Draws an SkSL shader over its constraint. Coordinates given to main() start from the top left corner of the
+// constraint. These uniforms are fed by the node if the shader declares them:
+// - `uniform float u_time;` the time of the node, which AnimatedShader advances
+// - `uniform float2 u_resolution;` the size of the constraint
+#[derive(Clone)]
+pub struct ShaderNode {
+  // Get one from Context::get_effect() or compile one with ShaderNode::compile(). Nothing is drawn if not given.
+  pub effect: Option<RuntimeEffect>,
+
+  pub uniforms: Vec<(String, Uniform)>,
+  pub blend_mode: BlendMode,
+
+  // In seconds. Wrap the node in AnimatedShader to play it.
+  pub time: f32,
+}
+
+impl Default for ShaderNode {
+  fn default() -> Self {
+    Self {
+      effect: None,
+      uniforms: vec![],
+      blend_mode: BlendMode::SrcOver,
+      time: 0f32,
+    }
+  }
+}
+
+impl Debug for ShaderNode {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("ShaderNode")
+      .field("uniforms", &self.uniforms)
+      .field("blend_mode", &self.blend_mode)
+      .field("time", &self.time)
+      .finish_non_exhaustive()
+  }
+}
+
+impl ShaderNode {
+  // Panics with the compile errors if the given SkSL source is invalid
+  pub fn compile(sksl: &str) -> RuntimeEffect {
+    RuntimeEffect::make_for_shader(sksl, None).unwrap_or_else(|err| panic!("Failed to compile SkSL: {err}"))
+  }
+
+  // Lays out the uniforms the way the given effect declares them. Uniforms not declared by the effect are ignored.
+  fn make_uniform_data(&self, effect: &RuntimeEffect, size: (f32, f32)) -> Data {
+    let mut data = vec![0u8; effect.uniform_size()];
+
+    let builtins = [
+      ("u_time".to_owned(), Uniform::Float(self.time)),
+      ("u_resolution".to_owned(), Uniform::Float2([size.0, size.1])),
+    ];
+
+    for (name, value) in builtins.iter().chain(&self.uniforms) {
+      let Some(uniform) = effect.uniforms().iter().find(|uniform| uniform.name() == name) else {
+        continue;
+      };
+
+      let bytes = value.to_bytes();
+
+      // Preconditions
+      assert_eq!(
+        bytes.len(),
+        uniform.size_in_bytes(),
+        "uniform {name} must have the type declared in SkSL"
+      );
+
+      data[uniform.offset()..uniform.offset() + bytes.len()].copy_from_slice(&bytes);
+    }
+
+    Data::new_copy(&data)
+  }
+}
+
+impl Node for ShaderNode {
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let Some(effect) = &self.effect else {
+      return;
+    };
+
+    let shader = effect.make_shader(
+      self.make_uniform_data(effect, constraint.size),
+      &[],
+      &Matrix::translate(constraint.position),
+    );
+
+    let mut paint = Paint::default();
+    paint.set_shader(shader).set_blend_mode(self.blend_mode);
+
+    canvas.draw_rect(
+      Rect::from_xywh(
+        constraint.position.0,
+        constraint.position.1,
+        constraint.size.0,
+        constraint.size.1,
+      ),
+      &paint,
+    );
+  }
+}
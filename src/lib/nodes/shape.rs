@@ -0,0 +1,13 @@
+use crate::models::{Box2D, Stroke, Style};
+use skia_safe::Paint;
+
+// Fills the shape first so that its stroke is drawn on top
+pub(super) fn draw_shape(fill: Option<&Style>, stroke: Option<&Stroke>, rect: Box2D, draw: impl Fn(&Paint)) {
+  if let Some(style) = fill {
+    draw(&style.make_paint(rect));
+  }
+
+  if let Some(stroke) = stroke {
+    draw(&stroke.make_paint(rect));
+  }
+}
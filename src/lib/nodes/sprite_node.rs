@@ -0,0 +1,94 @@
+use super::{ImageSampling, Node};
+use crate::{
+  common::SpriteAtlas,
+  models::{Box2D, Constraints},
+};
+use skia_safe::{canvas::SrcRectConstraint, Canvas, Paint, Rect};
+use std::{
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+// Draws a named frame of a sprite atlas stretched to its rect
+#[derive(Clone, Default)]
+pub struct SpriteNode {
+  // Get one from Context::get_atlas(). Nothing is drawn if not given.
+  pub atlas: Option<Rc<SpriteAtlas>>,
+
+  pub frame: String,
+
+  // Clockwise in degrees around the center of the rect
+  pub rotation: f32,
+
+  pub flip_x: bool,
+  pub flip_y: bool,
+  pub sampling: ImageSampling,
+}
+
+impl Debug for SpriteNode {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("SpriteNode")
+      .field("frame", &self.frame)
+      .field("rotation", &self.rotation)
+      .field("flip_x", &self.flip_x)
+      .field("flip_y", &self.flip_y)
+      .field("sampling", &self.sampling)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Node for SpriteNode {
+  // Keeps the aspect ratio of the frame while shrinking it to fit in the constraints
+  fn measure(&self, constraints: Constraints) -> (f32, f32) {
+    let Some(frame) = self.atlas.as_ref().and_then(|atlas| atlas.get_frame(&self.frame)) else {
+      return constraints.constrain((0f32, 0f32));
+    };
+
+    let (width, height) = (frame.width() as f32, frame.height() as f32);
+    let scale = (constraints.max.0 / width).min(constraints.max.1 / height).min(1f32);
+    constraints.constrain((width * scale, height * scale))
+  }
+
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    let Some(atlas) = &self.atlas else {
+      return;
+    };
+
+    let Some(frame) = atlas.get_frame(&self.frame) else {
+      return;
+    };
+
+    canvas.save();
+
+    canvas.translate((
+      constraint.position.0 + constraint.size.0 * 0.5f32,
+      constraint.position.1 + constraint.size.1 * 0.5f32,
+    ));
+
+    canvas.rotate(self.rotation, None);
+    canvas.scale((
+      if self.flip_x { -1f32 } else { 1f32 },
+      if self.flip_y { -1f32 } else { 1f32 },
+    ));
+
+    // Strict so that neighbouring frames in the atlas never bleed into this one
+    canvas.draw_image_rect_with_sampling_options(
+      atlas.get_image(),
+      Some((
+        &Rect::from_xywh(frame.left as _, frame.top as _, frame.width() as _, frame.height() as _),
+        SrcRectConstraint::Strict,
+      )),
+      Rect::from_xywh(
+        constraint.size.0 * -0.5f32,
+        constraint.size.1 * -0.5f32,
+        constraint.size.0,
+        constraint.size.1,
+      ),
+      self.sampling,
+      &Paint::default(),
+    );
+
+    canvas.restore();
+  }
+}
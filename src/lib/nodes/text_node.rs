@@ -0,0 +1,222 @@
+use super::Node;
+use crate::models::{Box2D, Constraints};
+use skia_safe::{
+  textlayout::{self, FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, TextStyle, TypefaceFontProvider},
+  Canvas, Color, FontMgr, Typeface, TypefaceId,
+};
+use std::{
+  cell::RefCell,
+  fmt::{self, Debug, Formatter},
+};
+
+// Falls back to the fonts of the system for the glyphs missing from the given typefaces
+pub(crate) fn make_font_collection<'a>(typefaces: impl IntoIterator<Item = &'a Typeface>) -> FontCollection {
+  let mut font_collection = FontCollection::new();
+  font_collection.set_default_font_manager(FontMgr::new(), None);
+  let mut font_provider = TypefaceFontProvider::new();
+
+  for typeface in typefaces {
+    font_provider.register_typeface(typeface.clone(), Some(&typeface.family_name()));
+  }
+
+  font_collection.set_asset_font_manager(Some(font_provider.into()));
+  font_collection
+}
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TextAlign {
+  #[default]
+  Left,
+  Center,
+  Right,
+}
+
+impl From<TextAlign> for textlayout::TextAlign {
+  fn from(value: TextAlign) -> Self {
+    match value {
+      TextAlign::Left => textlayout::TextAlign::Left,
+      TextAlign::Center => textlayout::TextAlign::Center,
+      TextAlign::Right => textlayout::TextAlign::Right,
+    }
+  }
+}
+
+// Text is wrapped at the width of its rect unless it runs out of lines
+#[derive(Clone)]
+pub struct TextNode {
+  pub text: String,
+
+  // Get one from Context::get_typeface(). Defaults to the typeface of the system if not given.
+  pub typeface: Option<Typeface>,
+
+  // Get it from Context::get_font_collection(), which has the typefaces of assets/fonts/ and keeps the text shaped with
+  // them across text nodes. A text node makes its own if not given.
+  pub font_collection: Option<FontCollection>,
+
+  pub size: f32,
+  pub color: Color,
+  pub align: TextAlign,
+
+  // No limit if not given
+  pub max_lines: Option<usize>,
+
+  // Whether to end the last line with an ellipsis when the text does not fit in max_lines
+  pub ellipsis: bool,
+
+  // Leave it to the default
+  pub paragraph_cache: ParagraphCache,
+}
+
+impl Default for TextNode {
+  fn default() -> Self {
+    Self {
+      text: String::new(),
+      typeface: None,
+      font_collection: None,
+      size: 16f32,
+      color: Color::WHITE,
+      align: TextAlign::default(),
+      max_lines: None,
+      ellipsis: false,
+      paragraph_cache: ParagraphCache::default(),
+    }
+  }
+}
+
+impl Debug for TextNode {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("TextNode")
+      .field("text", &self.text)
+      .field("size", &self.size)
+      .field("color", &self.color)
+      .field("align", &self.align)
+      .field("max_lines", &self.max_lines)
+      .field("ellipsis", &self.ellipsis)
+      .finish_non_exhaustive()
+  }
+}
+
+impl TextNode {
+  fn get_paragraph_key(&self) -> ParagraphKey {
+    ParagraphKey {
+      text: self.text.clone(),
+      typeface_id: self.typeface.as_ref().map(Typeface::unique_id),
+      size: self.size,
+      color: self.color,
+      align: self.align,
+      max_lines: self.max_lines,
+      ellipsis: self.ellipsis,
+    }
+  }
+
+  // Shapes the text, which is broken into lines by the layout
+  fn make_paragraph(&self) -> Paragraph {
+    let font_collection = match &self.font_collection {
+      Some(font_collection) => font_collection.clone(),
+      None => make_font_collection(&self.typeface),
+    };
+
+    let font_families = self.typeface.iter().map(Typeface::family_name).collect::<Vec<_>>();
+
+    let mut text_style = TextStyle::new();
+
+    text_style
+      .set_font_size(self.size)
+      .set_color(self.color)
+      .set_font_families(&font_families);
+
+    let mut paragraph_style = ParagraphStyle::new();
+
+    paragraph_style
+      .set_text_style(&text_style)
+      .set_text_align(self.align.into())
+      .set_max_lines(self.max_lines);
+
+    if self.ellipsis {
+      paragraph_style.set_ellipsis("\u{2026}");
+    }
+
+    ParagraphBuilder::new(&paragraph_style, font_collection)
+      .add_text(&self.text)
+      .build()
+  }
+
+  // Calls the given callback with the paragraph broken into lines which fit in the given width. The text is only shaped
+  // again when the props change, and only broken into lines again when the width changes too.
+  fn with_paragraph<R>(&self, width: f32, callback: impl FnOnce(&Paragraph) -> R) -> R {
+    let key = self.get_paragraph_key();
+    let mut cache = self.paragraph_cache.0.borrow_mut();
+
+    if !cache.as_ref().is_some_and(|cached| cached.key == key) {
+      *cache = Some(CachedParagraph {
+        key,
+        width: None,
+        paragraph: self.make_paragraph(),
+      });
+    }
+
+    let cached = cache.as_mut().unwrap();
+
+    if cached.width != Some(width) {
+      cached.paragraph.layout(width);
+      cached.width = Some(width);
+    }
+
+    callback(&cached.paragraph)
+  }
+}
+
+impl Node for TextNode {
+  fn measure(&self, constraints: Constraints) -> (f32, f32) {
+    self.with_paragraph(constraints.max.0, |paragraph| {
+      constraints.constrain((paragraph.longest_line().ceil(), paragraph.height().ceil()))
+    })
+  }
+
+  fn draw(&self, canvas: &Canvas, constraint: Box2D) {
+    self.with_paragraph(constraint.size.0, |paragraph| {
+      paragraph.paint(canvas, constraint.position)
+    });
+  }
+}
+
+// What a paragraph is shaped from
+#[derive(Clone, Debug, PartialEq)]
+struct ParagraphKey {
+  text: String,
+  typeface_id: Option<TypefaceId>,
+  size: f32,
+  color: Color,
+  align: TextAlign,
+  max_lines: Option<usize>,
+  ellipsis: bool,
+}
+
+struct CachedParagraph {
+  key: ParagraphKey,
+
+  // The width which the paragraph is broken into lines for, or None if not broken yet
+  width: Option<f32>,
+
+  paragraph: Paragraph,
+}
+
+// The paragraph of a text node laid out last time, which is shared by its measure and draw. Clones start empty.
+#[derive(Default)]
+pub struct ParagraphCache(RefCell<Option<CachedParagraph>>);
+
+impl Clone for ParagraphCache {
+  fn clone(&self) -> Self {
+    Self::default()
+  }
+}
+
+impl Debug for ParagraphCache {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("ParagraphCache")
+      .field("key", &self.0.borrow().as_ref().map(|cached| &cached.key))
+      .finish_non_exhaustive()
+  }
+}
@@ -0,0 +1,157 @@
+use sdl2::{
+  controller::{Axis, Button},
+  event::Event,
+  keyboard::{Keycode, Mod},
+  mouse::{MouseButton, MouseState, MouseWheelDirection},
+};
+
+// Builders of the events pushed to a simulator. Fields which the engine does not read, e.g. timestamps and window IDs,
+// are left at zero.
+
+pub fn key_down(keycode: Keycode) -> Event {
+  key_down_with_mod(keycode, Mod::NOMOD)
+}
+
+pub fn key_down_with_mod(keycode: Keycode, keymod: Mod) -> Event {
+  Event::KeyDown {
+    timestamp: 0,
+    window_id: 0,
+    keycode: Some(keycode),
+    scancode: None,
+    keymod,
+    repeat: false,
+  }
+}
+
+// Sent again and again while the key is held down
+pub fn key_repeat(keycode: Keycode) -> Event {
+  Event::KeyDown {
+    timestamp: 0,
+    window_id: 0,
+    keycode: Some(keycode),
+    scancode: None,
+    keymod: Mod::NOMOD,
+    repeat: true,
+  }
+}
+
+pub fn key_up(keycode: Keycode) -> Event {
+  Event::KeyUp {
+    timestamp: 0,
+    window_id: 0,
+    keycode: Some(keycode),
+    scancode: None,
+    keymod: Mod::NOMOD,
+    repeat: false,
+  }
+}
+
+// Of the left mouse button
+pub fn mouse_button_down(x: i32, y: i32) -> Event {
+  Event::MouseButtonDown {
+    timestamp: 0,
+    window_id: 0,
+    which: 0,
+    mouse_btn: MouseButton::Left,
+    clicks: 1,
+    x,
+    y,
+  }
+}
+
+// Of the left mouse button
+pub fn mouse_button_up(x: i32, y: i32) -> Event {
+  Event::MouseButtonUp {
+    timestamp: 0,
+    window_id: 0,
+    which: 0,
+    mouse_btn: MouseButton::Left,
+    clicks: 1,
+    x,
+    y,
+  }
+}
+
+// Moved to the given position by the given distance since the previous motion
+pub fn mouse_motion(x: i32, y: i32, rel: (i32, i32)) -> Event {
+  Event::MouseMotion {
+    timestamp: 0,
+    window_id: 0,
+    which: 0,
+    mousestate: MouseState::from_sdl_state(0),
+    x,
+    y,
+    xrel: rel.0,
+    yrel: rel.1,
+  }
+}
+
+// Positive y scrolls up
+pub fn mouse_wheel(y: i32) -> Event {
+  Event::MouseWheel {
+    timestamp: 0,
+    window_id: 0,
+    which: 0,
+    x: 0,
+    y,
+    direction: MouseWheelDirection::Normal,
+  }
+}
+
+// Positions of fingers are normalized to the window, e.g. (1, 1) is the bottom right corner
+pub fn finger_down(x: f32, y: f32) -> Event {
+  Event::FingerDown {
+    timestamp: 0,
+    touch_id: 0,
+    finger_id: 1,
+    x,
+    y,
+    dx: 0f32,
+    dy: 0f32,
+    pressure: 1f32,
+  }
+}
+
+pub fn finger_motion(x: f32, y: f32) -> Event {
+  Event::FingerMotion {
+    timestamp: 0,
+    touch_id: 0,
+    finger_id: 1,
+    x,
+    y,
+    dx: 0f32,
+    dy: 0f32,
+    pressure: 1f32,
+  }
+}
+
+pub fn finger_up(x: f32, y: f32) -> Event {
+  Event::FingerUp {
+    timestamp: 0,
+    touch_id: 0,
+    finger_id: 1,
+    x,
+    y,
+    dx: 0f32,
+    dy: 0f32,
+    pressure: 1f32,
+  }
+}
+
+// The given controller is the instance ID of a connected controller
+pub fn controller_button_down(which: u32, button: Button) -> Event {
+  Event::ControllerButtonDown {
+    timestamp: 0,
+    which,
+    button,
+  }
+}
+
+pub fn controller_axis_motion(which: u32, axis: Axis, value: i16) -> Event {
+  Event::ControllerAxisMotion {
+    timestamp: 0,
+    which,
+    axis,
+    value,
+  }
+}
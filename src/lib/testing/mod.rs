@@ -0,0 +1,8 @@
+pub mod events;
+pub mod probe;
+pub mod simulator;
+pub mod snapshot;
+
+pub use probe::{get_event_kind, probe, rect, take, Log, Probe};
+pub use simulator::Simulator;
+pub use snapshot::{get_pixel, Snapshot};
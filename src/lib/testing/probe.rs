@@ -0,0 +1,126 @@
+use crate::{
+  models::{Box2D, PointerEvent},
+  nodes::Node,
+  Context,
+};
+use sdl2::event::Event;
+use skia_safe::Canvas;
+use std::{
+  cell::{Cell, RefCell},
+  fmt::{self, Debug, Formatter},
+  rc::Rc,
+};
+
+pub type Log = Rc<RefCell<Vec<String>>>;
+
+// A node which records what the engine does with it. Clone its cells before it is moved into the view tree.
+pub struct Probe {
+  // Prefixes its entries in the logs
+  pub name: &'static str,
+
+  pub size: (f32, f32),
+
+  // Stops the propagation of each event reaching it
+  pub is_stopping: bool,
+
+  // The rect it was drawn in last
+  pub rect: Rc<Cell<Box2D>>,
+
+  // The position of the last press of a mouse button reaching it
+  pub clicked_at: Rc<Cell<Option<(i32, i32)>>>,
+
+  // Logs the events reaching it, e.g. "panel:down" or "panel:hover(10.0, 10.0)"
+  pub log: Log,
+
+  // Logs its name each time it is drawn, e.g. to tell the order in which its siblings are drawn
+  pub draws: Log,
+}
+
+impl Default for Probe {
+  fn default() -> Self {
+    Self {
+      name: "probe",
+      size: (f32::MAX, f32::MAX),
+      is_stopping: false,
+      rect: Rc::new(Cell::new(Box2D::default())),
+      clicked_at: Rc::new(Cell::new(None)),
+      log: Log::default(),
+      draws: Log::default(),
+    }
+  }
+}
+
+impl Debug for Probe {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Probe")
+      .field("name", &self.name)
+      .field("size", &self.size)
+      .field("is_stopping", &self.is_stopping)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Node for Probe {
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    if let Event::MouseButtonDown { x, y, .. } = *event {
+      self.clicked_at.set(Some((x, y)));
+    }
+
+    self
+      .log
+      .borrow_mut()
+      .push(format!("{}:{}", self.name, get_event_kind(event)));
+
+    if self.is_stopping {
+      context.stop_propagation();
+    }
+  }
+
+  fn on_pointer_event(&mut self, _context: &mut Context, event: &PointerEvent) {
+    self.log.borrow_mut().push(match event {
+      PointerEvent::Enter => format!("{}:enter", self.name),
+      PointerEvent::Hover { position } => format!("{}:hover{:?}", self.name, position),
+      PointerEvent::Leave => format!("{}:leave", self.name),
+    });
+  }
+
+  fn get_size(&self) -> (f32, f32) {
+    self.size
+  }
+
+  fn draw(&self, _canvas: &Canvas, constraint: Box2D) {
+    self.rect.set(constraint);
+    self.draws.borrow_mut().push(self.name.to_owned());
+  }
+}
+
+// Returns the probe of the given size and the rect it is drawn in
+pub fn probe(size: (f32, f32)) -> (Probe, Rc<Cell<Box2D>>) {
+  let probe = Probe {
+    size,
+    ..Default::default()
+  };
+  let rect = Rc::clone(&probe.rect);
+  (probe, rect)
+}
+
+pub fn rect(position: (f32, f32), size: (f32, f32)) -> Box2D {
+  Box2D { position, size }
+}
+
+// Empties the given log and returns its entries
+pub fn take(log: &Log) -> Vec<String> {
+  log.borrow_mut().drain(..).collect()
+}
+
+// The name of the given event in the logs
+pub fn get_event_kind(event: &Event) -> &'static str {
+  match event {
+    Event::MouseMotion { .. } => "motion",
+    Event::MouseButtonDown { .. } => "down",
+    Event::MouseButtonUp { .. } => "up",
+    Event::KeyDown { .. } => "key",
+    _ => "other",
+  }
+}
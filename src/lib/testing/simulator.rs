@@ -0,0 +1,173 @@
+use crate::{common::Sharable, engine::TICK_DT, input::InputMap, models::Box2D, Context, Engine, View};
+use sdl2::{event::Event, GameControllerSubsystem};
+use skia_safe::{surfaces, Color, Image, Surface};
+use std::{
+  collections::VecDeque,
+  fmt::{self, Debug, Formatter},
+};
+
+pub struct Simulator {
+  context: Context,
+  child: Option<Sharable<View>>,
+  size: (u32, u32),
+  color: Color,
+  surface: Surface,
+  events: VecDeque<Event>,
+  tick_count: usize,
+  frame_count: usize,
+  frame: Option<Image>,
+}
+
+impl Debug for Simulator {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Simulator")
+      .field("size", &self.size)
+      .field("color", &self.color)
+      .field("events", &self.events)
+      .field("tick_count", &self.tick_count)
+      .field("frame_count", &self.frame_count)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Simulator {
+  // The duration of each tick in seconds, same as the one used by the game loop in app::run()
+  pub const TICK_DT: f32 = TICK_DT;
+
+  pub fn new(child: Option<Sharable<View>>, size: (u32, u32)) -> Self {
+    // Preconditions
+    assert_ne!(size.0, 0, "size.0 must be a positive integer");
+    assert_ne!(size.1, 0, "size.1 must be a positive integer");
+
+    Self {
+      context: Context::default(),
+      child,
+      size,
+      color: Color::BLACK,
+      surface: surfaces::raster_n32_premul((size.0 as _, size.1 as _)).unwrap(),
+      events: VecDeque::new(),
+      tick_count: 0,
+      frame_count: 0,
+      frame: None,
+    }
+  }
+
+  // Loads fonts from assets/fonts/ like app::run() so that layouts can get typefaces from the context
+  pub fn with_fonts(mut self) -> Self {
+    self.context.init_fonts();
+    self
+  }
+
+  // Loads images from assets/images/ and sprite atlases from assets/atlases/ like app::run() so that layouts can get
+  // them from the context
+  pub fn with_images(mut self) -> Self {
+    self.context.init_images();
+    self
+  }
+
+  // Compiles shaders from assets/shaders/ like app::run() so that layouts can get effects from the context
+  pub fn with_effects(mut self) -> Self {
+    self.context.init_effects();
+    self
+  }
+
+  pub fn with_color(mut self, color: Color) -> Self {
+    self.color = color;
+    self
+  }
+
+  pub fn with_seed(mut self, seed: u64) -> Self {
+    self.context.init_rng(seed);
+    self
+  }
+
+  pub fn with_input_map(mut self, input_map: InputMap) -> Self {
+    self.context.init_input(input_map);
+    self
+  }
+
+  // Opens game controllers when plugged in like app::run(), e.g. virtual joysticks attached by tests
+  pub fn with_controllers(mut self, subsystem: GameControllerSubsystem) -> Self {
+    self.context.init_controllers(subsystem);
+    self
+  }
+
+  pub fn get_context(&mut self) -> &mut Context {
+    &mut self.context
+  }
+
+  pub const fn get_tick_count(&self) -> usize {
+    self.tick_count
+  }
+
+  pub const fn get_frame_count(&self) -> usize {
+    self.frame_count
+  }
+
+  pub const fn get_frame(&self) -> Option<&Image> {
+    self.frame.as_ref()
+  }
+
+  // The given event will be dispatched to the view tree right before the next tick
+  pub fn push_event(&mut self, event: Event) {
+    self.events.push_back(event);
+  }
+
+  pub fn dispatch_events(&mut self) {
+    while let Some(event) = self.events.pop_front() {
+      if let Some(child) = &mut self.child {
+        Engine::on_event_in_root(child, &mut self.context, &event);
+      }
+    }
+  }
+
+  pub fn tick(&mut self) {
+    self.advance(1);
+  }
+
+  pub fn advance(&mut self, ticks: usize) {
+    for _ in 0..ticks {
+      self.dispatch_events();
+
+      if let Some(child) = &mut self.child {
+        Engine::tick_in_root(child, &mut self.context, TICK_DT);
+      }
+
+      self.tick_count += 1;
+    }
+
+    Engine::sweep(&mut self.context);
+  }
+
+  // Runs a whole frame the same way as the game loop in app::run(), assuming dt seconds have passed since the previous
+  // frame
+  pub fn advance_frame(&mut self, dt: f32) -> &Image {
+    self.dispatch_events();
+
+    if let Some(child) = &mut self.child {
+      self.tick_count += Engine::advance_root(child, &mut self.context, dt);
+    }
+
+    self.draw()
+  }
+
+  pub fn draw(&mut self) -> &Image {
+    let canvas = self.surface.canvas();
+    canvas.clear(self.color);
+
+    if let Some(child) = &mut self.child {
+      let rect = Box2D {
+        position: (0f32, 0f32),
+        size: (self.size.0 as _, self.size.1 as _),
+      };
+
+      Engine::layout_root(child, &mut self.context, rect);
+      Engine::draw_root(child, &mut self.context, canvas, rect);
+    }
+
+    Engine::sweep(&mut self.context);
+    self.frame_count += 1;
+    self.frame.insert(self.surface.image_snapshot())
+  }
+}
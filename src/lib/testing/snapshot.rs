@@ -0,0 +1,201 @@
+use crate::{common::Sharable, context::CONTEXT, models::Box2D, Engine, View};
+use skia_safe::{
+  images, surfaces, AlphaType, CachingHint, Color, ColorType, Data, EncodedImageFormat, Image, ImageInfo,
+};
+use std::{
+  env,
+  fmt::{self, Debug, Formatter},
+  fs,
+  path::Path,
+};
+
+// Set this environment variable to record missing reference images and overwrite the rest with the actual ones.
+// Otherwise a missing reference image fails the snapshot, so that every reference image must be committed.
+const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+pub fn render(child: &mut Option<Sharable<View>>, size: (u32, u32), color: Color) -> Image {
+  // Preconditions
+  assert_ne!(size.0, 0, "size.0 must be a positive integer");
+  assert_ne!(size.1, 0, "size.1 must be a positive integer");
+
+  let mut surface = surfaces::raster_n32_premul((size.0 as _, size.1 as _)).unwrap();
+  let canvas = surface.canvas();
+  canvas.clear(color);
+
+  if let Some(child) = child {
+    let constraint = Box2D {
+      position: (0f32, 0f32),
+      size: (size.0 as _, size.1 as _),
+    };
+
+    CONTEXT.with_borrow_mut(|context| {
+      Engine::layout_root(child, context, constraint);
+      Engine::draw_root(child, context, canvas, constraint);
+    });
+  }
+
+  surface.image_snapshot()
+}
+
+pub fn encode_png(image: &Image) -> Vec<u8> {
+  image
+    .encode(None, EncodedImageFormat::PNG, None)
+    .unwrap()
+    .as_bytes()
+    .to_vec()
+}
+
+pub struct Snapshot<'a> {
+  pub dir: &'a str,
+  pub size: (u32, u32),
+  pub color: Color,
+
+  // The maximum difference allowed in each color channel of a pixel before it is counted as mismatched
+  pub tolerance: u8,
+
+  // The maximum number of mismatched pixels allowed before the snapshot fails
+  pub max_mismatches: usize,
+}
+
+impl<'a> Default for Snapshot<'a> {
+  fn default() -> Self {
+    Self {
+      dir: "tests/snapshots/",
+      size: (256, 256),
+      color: Color::BLACK,
+      tolerance: 0,
+      max_mismatches: 0,
+    }
+  }
+}
+
+impl<'a> Debug for Snapshot<'a> {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("Snapshot")
+      .field("dir", &self.dir)
+      .field("size", &self.size)
+      .field("color", &self.color)
+      .field("tolerance", &self.tolerance)
+      .field("max_mismatches", &self.max_mismatches)
+      .finish()
+  }
+}
+
+impl<'a> Snapshot<'a> {
+  pub fn assert_matches(&self, name: &str, child: &mut Option<Sharable<View>>) {
+    self.assert_image_matches(name, &render(child, self.size, self.color));
+  }
+
+  pub fn assert_image_matches(&self, name: &str, actual: &Image) {
+    let dir = Path::new(self.dir);
+    let reference_path = dir.join(format!("{name}.png"));
+    let actual_path = dir.join(format!("{name}.actual.png"));
+    let diff_path = dir.join(format!("{name}.diff.png"));
+
+    // Clean up the artifacts from the previous failed run
+    let _ = fs::remove_file(&actual_path);
+    let _ = fs::remove_file(&diff_path);
+
+    if env::var_os(UPDATE_ENV).is_some() {
+      fs::create_dir_all(dir).unwrap();
+      fs::write(&reference_path, encode_png(actual)).unwrap();
+      return;
+    }
+
+    if !reference_path.exists() {
+      fs::create_dir_all(dir).unwrap();
+      fs::write(&actual_path, encode_png(actual)).unwrap();
+
+      panic!(
+        "Snapshot {name} has no reference image {}, see {} and rerun with {UPDATE_ENV}=1 to record it",
+        reference_path.display(),
+        actual_path.display()
+      );
+    }
+
+    let expected =
+      Image::from_encoded(Data::new_copy(&fs::read(&reference_path).unwrap_or_else(|err| {
+        panic!("Failed to read the reference image {}: {err}", reference_path.display())
+      })))
+      .unwrap();
+
+    if expected.dimensions() != actual.dimensions() {
+      fs::write(&actual_path, encode_png(actual)).unwrap();
+
+      panic!(
+        "Snapshot {name} has size {:?} but the reference image has size {:?}, see {}",
+        (actual.width(), actual.height()),
+        (expected.width(), expected.height()),
+        actual_path.display()
+      );
+    }
+
+    let expected_pixels = read_pixels(&expected);
+    let actual_pixels = read_pixels(actual);
+    let mut diff_pixels = Vec::with_capacity(actual_pixels.len());
+    let mut mismatches = 0;
+
+    for (expected, actual) in expected_pixels.chunks_exact(4).zip(actual_pixels.chunks_exact(4)) {
+      if expected
+        .iter()
+        .zip(actual)
+        .any(|(&expected, &actual)| expected.abs_diff(actual) > self.tolerance)
+      {
+        // Highlight the mismatched pixel
+        diff_pixels.extend_from_slice(&[255, 0, 0, 255]);
+        mismatches += 1;
+      } else {
+        // Dim the matched pixel so that the mismatched pixels stand out
+        diff_pixels.extend_from_slice(&[actual[0] >> 2, actual[1] >> 2, actual[2] >> 2, 255]);
+      }
+    }
+
+    if mismatches > self.max_mismatches {
+      fs::write(&actual_path, encode_png(actual)).unwrap();
+
+      let info = make_image_info(actual);
+      let row_bytes = info.min_row_bytes();
+      let diff = images::raster_from_data(&info, Data::new_copy(&diff_pixels), row_bytes).unwrap();
+      fs::write(&diff_path, encode_png(&diff)).unwrap();
+
+      panic!(
+        "Snapshot {name} has {mismatches} mismatched pixels (max {}) with tolerance {}, see {} and {}",
+        self.max_mismatches,
+        self.tolerance,
+        actual_path.display(),
+        diff_path.display()
+      );
+    }
+  }
+}
+
+fn make_image_info(image: &Image) -> ImageInfo {
+  ImageInfo::new(image.dimensions(), ColorType::RGBA8888, AlphaType::Unpremul, None)
+}
+
+fn read_pixels(image: &Image) -> Vec<u8> {
+  let info = make_image_info(image);
+  let row_bytes = info.min_row_bytes();
+  let mut pixels = vec![0u8; row_bytes * image.height() as usize];
+
+  assert!(
+    image.read_pixels(&info, &mut pixels, row_bytes, (0, 0), CachingHint::Disallow),
+    "Failed to read the pixels of the image"
+  );
+
+  pixels
+}
+
+// Unpremultiplied RGBA of the pixel at the given position
+pub fn get_pixel(image: &Image, x: i32, y: i32) -> [u8; 4] {
+  let info = ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Unpremul, None);
+  let mut pixel = [0u8; 4];
+
+  assert!(
+    image.read_pixels(&info, &mut pixel, 4, (x, y), CachingHint::Disallow),
+    "Failed to read the pixel at ({x}, {y}) of the image"
+  );
+
+  pixel
+}
@@ -1,5 +1,6 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod controls;
 mod layouts;
 mod models;
 mod pages;
@@ -9,22 +10,42 @@ use skia_test::{
   layouts::{app, App},
   view::IntoViewFromStatefulLayout,
 };
+use std::env;
+use tinyrand::Rand;
+use tinyrand_std::ClockSeed;
 
 fn main() {
+  // Log the seed so that this run can be replayed with `--seed`
+  let seed = parse_seed(env::args().skip(1)).unwrap_or_else(|| ClockSeed::default().next_u64());
+  println!("Seed: {seed}");
+
   app::run(App {
     title: "Snake",
     size: (830, 900),
     play_audio: true,
+    seed: Some(seed),
+    input_map: controls::load(),
     child: GamePage.into_view(),
     ..Default::default()
   });
 }
 
+// Accepts either `--seed <seed>` or `--seed=<seed>` to replay a previous run
+fn parse_seed(mut args: impl Iterator<Item = String>) -> Option<u64> {
+  while let Some(arg) = args.next() {
+    let seed = if arg == "--seed" {
+      args.next().expect("--seed requires a value")
+    } else if let Some(seed) = arg.strip_prefix("--seed=") {
+      seed.to_owned()
+    } else {
+      continue;
+    };
+
+    return Some(seed.parse().expect("seed must be an unsigned 64-bit integer"));
+  }
+
+  None
+}
+
 // TODO: Enhancement
-// - Generate a key for each instance of view instead of manually specify the key
-// - The data type of key should be usize instead of String
 // - Engine::state_map should be Vec instead of HashMap
-// - make() trait methods should only be called when state changes
-//   - lazily call make() trait method
-//   - caching if state not changed
-//   - invalidate the cache if state changes
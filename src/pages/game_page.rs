@@ -15,11 +15,7 @@ use std::{
 pub(crate) struct GamePage;
 
 impl StatefulLayout for GamePage {
-  fn get_key(&self) -> &str {
-    "pages/game_page"
-  }
-
-  fn make_state(&mut self) -> Rc<RefCell<dyn State>> {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
     Rc::new(RefCell::new(GamePageState::new()))
   }
 }
@@ -0,0 +1,158 @@
+use sdl2::{event::Event, keyboard::Keycode};
+use skia_safe::Color;
+use skia_test::{
+  animations::Easing,
+  common::Sharable,
+  layouts::{stateful_layout::State, Animated, Children, StatefulLayout},
+  models::Box2D,
+  testing::{events::key_down, take, Log, Probe, Simulator},
+  view::{IntoViewFromNode, IntoViewFromStatefulLayout},
+  Context, View,
+};
+use std::{
+  cell::{Cell, RefCell},
+  rc::Rc,
+};
+
+// Moves its child to the right, fades it and changes its color on each key press
+#[derive(Default)]
+struct Toggle {
+  rect: Rc<Cell<Box2D>>,
+  draws: Log,
+}
+
+impl StatefulLayout for Toggle {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(ToggleState {
+      presses: 0,
+      is_faded: false,
+      rect: Rc::clone(&self.rect),
+      draws: Rc::clone(&self.draws),
+    }))
+  }
+}
+
+struct ToggleState {
+  presses: usize,
+  is_faded: bool,
+  rect: Rc<Cell<Box2D>>,
+  draws: Log,
+}
+
+impl State for ToggleState {
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    if let Event::KeyDown { .. } = event {
+      context.set_state(|| {
+        self.presses += 1;
+        self.is_faded = !self.is_faded;
+      });
+    }
+  }
+
+  fn arrange(&self, rect: Box2D, _child: &mut Children) -> Box2D {
+    Box2D {
+      position: (rect.position.0 + 100f32 * self.presses as f32, rect.position.1),
+      size: (20f32, 20f32),
+    }
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    Animated {
+      duration: 0.25f32,
+      easing: Easing::Linear,
+      opacity: if self.is_faded { 0.5f32 } else { 1f32 },
+      color: Some(if self.is_faded { Color::BLACK } else { Color::WHITE }),
+      child: Probe {
+        name: if self.is_faded { "faded" } else { "opaque" },
+        rect: Rc::clone(&self.rect),
+        draws: Rc::clone(&self.draws),
+        ..Default::default()
+      }
+      .into_view(),
+    }
+    .into_view()
+  }
+}
+
+#[test]
+fn rect_changes_are_animated() {
+  let toggle = Toggle::default();
+  let rect = Rc::clone(&toggle.rect);
+  let mut simulator = Simulator::new(toggle.into_view(), (200, 100));
+
+  // Starts at the first rect without animating
+  simulator.draw();
+  assert_eq!(rect.get().position, (0f32, 0f32));
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.tick();
+  simulator.draw();
+  assert_eq!(rect.get().position, (0f32, 0f32));
+
+  // 120 ticks per second
+  simulator.advance(15);
+  simulator.draw();
+  assert!((rect.get().position.0 - 50f32).abs() < 1e-3f32);
+  assert_eq!(rect.get().size, (20f32, 20f32));
+
+  simulator.advance(15);
+  simulator.draw();
+  assert_eq!(rect.get().position, (100f32, 0f32));
+}
+
+#[test]
+fn retargeting_continues_from_the_current_rect() {
+  let toggle = Toggle::default();
+  let rect = Rc::clone(&toggle.rect);
+  let mut simulator = Simulator::new(toggle.into_view(), (300, 100));
+  simulator.draw();
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.tick();
+  simulator.draw();
+  simulator.advance(15);
+  simulator.draw();
+
+  // Halfway to 100 when it is asked to go to 200 instead
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.dispatch_events();
+  simulator.draw();
+  assert!((rect.get().position.0 - 50f32).abs() < 1e-3f32);
+
+  simulator.advance(15);
+  simulator.draw();
+  assert!((rect.get().position.0 - 125f32).abs() < 1e-3f32);
+
+  simulator.advance(15);
+  simulator.draw();
+  assert_eq!(rect.get().position, (200f32, 0f32));
+}
+
+#[test]
+fn new_children_are_made_with_new_props() {
+  let toggle = Toggle::default();
+  let draws = Rc::clone(&toggle.draws);
+  let mut simulator = Simulator::new(toggle.into_view(), (200, 100));
+
+  simulator.draw();
+  assert_eq!(take(&draws), ["opaque"]);
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.tick();
+  simulator.draw();
+  assert_eq!(take(&draws), ["faded"]);
+}
+
+#[test]
+#[should_panic(expected = "opacity must be between 0 and 1")]
+fn opacity_greater_than_one_is_rejected() {
+  let mut simulator = Simulator::new(
+    Animated {
+      opacity: 2f32,
+      ..Default::default()
+    }
+    .into_view(),
+    (10, 10),
+  );
+  simulator.draw();
+}
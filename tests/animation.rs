@@ -0,0 +1,290 @@
+use skia_safe::Color;
+use skia_test::{
+  animations::{Animation, CubicBezier, Easing, Lerp, Parallel, Repeat, Sequence, Spring, Tween},
+  common::Sharable,
+  layouts::{stateful_layout::State, Stack, StatefulLayout},
+  models::Box2D,
+  testing::{rect, Probe, Simulator},
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout},
+  Context, View,
+};
+use std::{
+  cell::{Cell, RefCell},
+  rc::Rc,
+};
+
+fn assert_near(actual: f32, expected: f32) {
+  assert!(
+    (actual - expected).abs() < 1e-3f32,
+    "expected {expected} but got {actual}"
+  );
+}
+
+#[test]
+fn values_are_interpolated() {
+  assert_near(10f32.lerp(&20f32, 0.25f32), 12.5f32);
+  assert_eq!((0f32, 10f32).lerp(&(10f32, 0f32), 0.5f32), (5f32, 5f32));
+
+  assert_eq!(
+    Box2D {
+      position: (0f32, 0f32),
+      size: (10f32, 20f32),
+    }
+    .lerp(
+      &Box2D {
+        position: (10f32, 10f32),
+        size: (30f32, 40f32),
+      },
+      0.5f32
+    ),
+    Box2D {
+      position: (5f32, 5f32),
+      size: (20f32, 30f32),
+    }
+  );
+
+  let from = Color::from_argb(255, 0, 100, 200);
+  let to = Color::from_argb(55, 200, 100, 0);
+  assert_eq!(from.lerp(&to, 0.5f32), Color::from_argb(155, 100, 100, 100));
+
+  // Channels do not wrap around when overshooting
+  assert_eq!(from.lerp(&to, 2f32), Color::from_argb(0, 255, 100, 0));
+}
+
+#[test]
+fn easing_curves_start_at_0_and_end_at_1() {
+  let easings = [
+    Easing::Linear,
+    Easing::InQuad,
+    Easing::OutQuad,
+    Easing::InOutQuad,
+    Easing::InCubic,
+    Easing::OutCubic,
+    Easing::InOutCubic,
+    Easing::InSine,
+    Easing::OutSine,
+    Easing::InOutSine,
+    Easing::InExpo,
+    Easing::OutExpo,
+    Easing::InOutExpo,
+    Easing::InBack,
+    Easing::OutBack,
+    Easing::InOutBack,
+    Easing::InElastic,
+    Easing::OutElastic,
+    Easing::InBounce,
+    Easing::OutBounce,
+    CubicBezier::EASE.into(),
+    CubicBezier::EASE_IN_OUT.into(),
+  ];
+
+  for easing in easings {
+    assert_near(easing.ease(0f32), 0f32);
+    assert_near(easing.ease(1f32), 1f32);
+  }
+
+  assert_near(Easing::InOutQuad.ease(0.5f32), 0.5f32);
+  assert!(Easing::InQuad.ease(0.5f32) < 0.5f32);
+  assert!(Easing::OutQuad.ease(0.5f32) > 0.5f32);
+  assert!(Easing::OutBack.ease(0.8f32) > 1f32);
+}
+
+#[test]
+fn cubic_bezier_matches_known_curves() {
+  // Control points on the diagonal make a straight line
+  let linear = CubicBezier::new(0.25f32, 0.25f32, 0.75f32, 0.75f32);
+
+  for t in [0.1f32, 0.3f32, 0.5f32, 0.9f32] {
+    assert_near(linear.ease(t), t);
+  }
+
+  // Symmetric around its midpoint
+  let ease_in_out = CubicBezier::EASE_IN_OUT;
+  assert_near(ease_in_out.ease(0.5f32), 0.5f32);
+  assert_near(ease_in_out.ease(0.2f32), 1f32 - ease_in_out.ease(0.8f32));
+}
+
+#[test]
+fn tween_plays_after_its_delay() {
+  let mut tween = Tween::new(0f32, 100f32, 1f32).with_delay(0.5f32);
+
+  assert_eq!(tween.advance(0.25f32), 0f32);
+  assert_eq!(tween.get_value(), 0f32);
+
+  tween.advance(0.75f32);
+  assert_near(tween.get_value(), 50f32);
+  assert!(!tween.is_finished());
+
+  assert_near(tween.advance(1f32), 0.5f32);
+  assert_eq!(tween.get_value(), 100f32);
+  assert!(tween.is_finished());
+
+  tween.reset();
+  assert_eq!(tween.get_value(), 0f32);
+  assert!(!tween.is_finished());
+}
+
+#[test]
+fn tween_repeats_and_yoyos() {
+  let mut tween = Tween::new(0f32, 10f32, 1f32)
+    .with_repeat(Repeat::Times(3))
+    .with_yoyo(true);
+
+  tween.advance(0.5f32);
+  assert_near(tween.get_value(), 5f32);
+
+  tween.advance(0.75f32);
+  assert_near(tween.get_value(), 7.5f32);
+
+  tween.advance(1f32);
+  assert_near(tween.get_value(), 2.5f32);
+
+  // Ends at the end of the last play, which goes forwards again
+  tween.advance(10f32);
+  assert!(tween.is_finished());
+  assert_eq!(tween.get_value(), 10f32);
+
+  let mut forever = Tween::new(0f32, 10f32, 1f32).with_repeat(Repeat::Forever);
+  assert_eq!(forever.advance(1000.25f32), 0f32);
+  assert!(!forever.is_finished());
+  assert_near(forever.get_value(), 2.5f32);
+}
+
+#[test]
+fn tween_retargets_from_its_current_value() {
+  let mut tween = Tween::new(0f32, 100f32, 1f32);
+  tween.advance(0.5f32);
+  tween.retarget(0f32);
+
+  assert_near(tween.get_value(), 50f32);
+
+  tween.advance(0.5f32);
+  assert_near(tween.get_value(), 25f32);
+}
+
+#[test]
+fn spring_overshoots_then_settles() {
+  let mut spring = Spring::new(0f32, 100f32).with_damping(8f32);
+  let mut max_value = 0f32;
+
+  for _ in 0..1000 {
+    spring.advance(1f32 / 60f32);
+    max_value = max_value.max(spring.get_value());
+  }
+
+  assert!(max_value > 100f32);
+  assert!(spring.is_finished());
+  assert_eq!(spring.get_value(), 100f32);
+}
+
+#[test]
+fn critically_damped_spring_does_not_overshoot() {
+  let mut spring = Spring::new(0f32, 100f32).with_stiffness(100f32).with_damping(20f32);
+
+  while !spring.is_finished() {
+    spring.advance(1f32 / 60f32);
+    assert!(spring.get_value() <= 100f32);
+  }
+}
+
+#[test]
+fn sequence_plays_one_after_another() {
+  let first = Rc::new(RefCell::new(Tween::new(0f32, 10f32, 1f32)));
+  let second = Rc::new(RefCell::new(Tween::new(10f32, 0f32, 1f32)));
+  let mut sequence = Sequence::new(vec![first.clone(), second.clone()]);
+
+  sequence.advance(0.5f32);
+  assert_near(first.borrow().get_value(), 5f32);
+  assert_eq!(second.borrow().get_value(), 10f32);
+
+  // Time left over by the first animation goes to the second one
+  sequence.advance(0.75f32);
+  assert!(first.borrow().is_finished());
+  assert_near(second.borrow().get_value(), 7.5f32);
+
+  assert_near(sequence.advance(1f32), 0.25f32);
+  assert!(sequence.is_finished());
+
+  sequence.reset();
+  assert_eq!(first.borrow().get_value(), 0f32);
+  assert_eq!(second.borrow().get_value(), 10f32);
+}
+
+#[test]
+fn parallel_plays_at_the_same_time() {
+  let short = Rc::new(RefCell::new(Tween::new(0f32, 10f32, 1f32)));
+  let long = Rc::new(RefCell::new(Tween::new(0f32, 10f32, 2f32)));
+  let mut parallel = Parallel::new(vec![short.clone(), long.clone()]);
+
+  parallel.advance(0.5f32);
+  assert_near(short.borrow().get_value(), 5f32);
+  assert_near(long.borrow().get_value(), 2.5f32);
+
+  assert_eq!(parallel.advance(1f32), 0f32);
+  assert!(short.borrow().is_finished());
+  assert!(!parallel.is_finished());
+
+  assert_near(parallel.advance(1f32), 0.5f32);
+  assert!(parallel.is_finished());
+}
+
+// The width of its child tells the alpha of the tween when it is drawn
+struct Fader {
+  rect: Rc<Cell<Box2D>>,
+}
+
+impl StatefulLayout for Fader {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(FaderState {
+      tween: Tween::new(0f32, 1f32, 0.5f32),
+      rect: Rc::clone(&self.rect),
+    }))
+  }
+}
+
+struct FaderState {
+  tween: Tween<f32>,
+  rect: Rc<Cell<Box2D>>,
+}
+
+impl State for FaderState {
+  fn tick(&mut self, context: &mut Context, dt: f32) {
+    if !self.tween.is_finished() {
+      self.tween.advance(dt);
+      context.mark_dirty();
+    }
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    Stack {
+      children: vec![Probe {
+        size: (self.tween.get_value(), 0f32),
+        rect: Rc::clone(&self.rect),
+        ..Default::default()
+      }
+      .into_view()
+      .into()],
+      ..Default::default()
+    }
+    .into_view()
+  }
+}
+
+#[test]
+fn tweens_are_advanced_by_ticks() {
+  let rect = Rc::new(Cell::new(rect((0f32, 0f32), (-1f32, -1f32))));
+
+  let mut simulator = Simulator::new(Fader { rect: Rc::clone(&rect) }.into_view(), (10, 10));
+
+  simulator.draw();
+  assert_eq!(rect.get().size.0, 0f32);
+
+  // 120 ticks per second
+  simulator.advance(30);
+  simulator.draw();
+  assert_near(rect.get().size.0, 0.5f32);
+
+  simulator.advance(60);
+  simulator.draw();
+  assert_eq!(rect.get().size.0, 1f32);
+}
@@ -0,0 +1,270 @@
+use sdl2::{
+  controller::{Axis, Button},
+  event::Event,
+};
+use skia_test::{
+  common::Sharable,
+  input::{controllers, Binding, InputMap},
+  layouts::{stateful_layout::State, StatefulLayout},
+  models::Box2D,
+  testing::{
+    events::{controller_axis_motion, controller_button_down},
+    take, Log, Simulator,
+  },
+  view::IntoViewFromStatefulLayout,
+  Context, View,
+};
+use std::{cell::RefCell, rc::Rc};
+
+// Logs the states of the turn_up action on each tick
+struct Steering {
+  log: Log,
+}
+
+impl StatefulLayout for Steering {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(SteeringState {
+      log: Rc::clone(&self.log),
+    }))
+  }
+}
+
+#[derive(Debug)]
+struct SteeringState {
+  log: Log,
+}
+
+impl State for SteeringState {
+  fn tick(&mut self, context: &mut Context, _dt: f32) {
+    self.log.borrow_mut().push(format!(
+      "pressed:{} just_pressed:{} just_released:{}",
+      context.is_action_pressed("turn_up"),
+      context.is_action_just_pressed("turn_up"),
+      context.is_action_just_released("turn_up")
+    ));
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    None
+  }
+}
+
+fn steering(log: &Log) -> Simulator {
+  Simulator::new(Steering { log: Rc::clone(log) }.into_view(), (100, 100)).with_input_map(
+    InputMap::default().with_action(
+      "turn_up",
+      [
+        Binding::ControllerButton(Button::DPadUp),
+        Binding::ControllerAxis {
+          axis: Axis::LeftY,
+          is_positive: false,
+        },
+      ],
+    ),
+  )
+}
+
+#[test]
+fn axis_values_within_the_dead_zone_are_zero() {
+  assert_eq!(controllers::apply_dead_zone(0, 0.25f32), 0f32);
+  assert_eq!(controllers::apply_dead_zone(8000, 0.25f32), 0f32);
+  assert_eq!(controllers::apply_dead_zone(-8000, 0.25f32), 0f32);
+  assert_eq!(controllers::apply_dead_zone(i16::MAX, 0.25f32), 1f32);
+  assert_eq!(controllers::apply_dead_zone(i16::MIN, 0.25f32), -1f32);
+
+  // Rescaled from the edge of the dead zone
+  assert!((controllers::apply_dead_zone(i16::MAX / 2, 0.5f32)).abs() < 0.001f32);
+  assert!((controllers::apply_dead_zone(i16::MAX / 4 * 3, 0.5f32) - 0.5f32).abs() < 0.001f32);
+}
+
+#[test]
+fn controllers_are_polled_from_their_events() {
+  let log = Log::default();
+  let mut simulator = steering(&log);
+
+  simulator.push_event(controller_button_down(1, Button::A));
+  simulator.push_event(controller_axis_motion(2, Axis::LeftX, i16::MIN));
+  simulator.push_event(controller_axis_motion(2, Axis::LeftY, 4000));
+  simulator.dispatch_events();
+
+  let controllers = simulator.get_context().get_controllers();
+  assert_eq!(controllers.len(), 2);
+  assert!(controllers.is_button_pressed(Button::A));
+  assert!(!controllers.is_button_pressed(Button::B));
+  assert!(controllers.get(1).unwrap().is_button_pressed(Button::A));
+  assert!(!controllers.get(2).unwrap().is_button_pressed(Button::A));
+  assert_eq!(controllers.get_axis(Axis::LeftX), -1f32);
+  assert_eq!(controllers.get_axis(Axis::LeftY), 0f32);
+
+  simulator.get_context().get_controllers_mut().set_dead_zone(0f32);
+  simulator.push_event(controller_axis_motion(2, Axis::LeftY, 4000));
+  simulator.push_event(Event::ControllerDeviceRemoved { timestamp: 0, which: 1 });
+  simulator.dispatch_events();
+
+  let controllers = simulator.get_context().get_controllers();
+  assert_eq!(controllers.len(), 1);
+  assert!(!controllers.is_button_pressed(Button::A));
+  assert!(controllers.get_axis(Axis::LeftY) > 0f32);
+}
+
+#[test]
+fn sticks_press_axis_bindings_beyond_the_dead_zone() {
+  let log = Log::default();
+  let mut simulator = steering(&log);
+
+  // Pushing the stick down or only slightly up does nothing
+  simulator.push_event(controller_axis_motion(0, Axis::LeftY, i16::MAX));
+  simulator.push_event(controller_axis_motion(0, Axis::LeftY, -4000));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(controller_axis_motion(0, Axis::LeftY, -20000));
+  simulator.push_event(controller_axis_motion(0, Axis::LeftY, i16::MIN));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(controller_axis_motion(0, Axis::LeftY, -1000));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:false just_pressed:false just_released:false",
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:false just_pressed:false just_released:true",
+    ]
+  );
+}
+
+#[test]
+fn unplugging_a_controller_releases_its_bindings() {
+  let log = Log::default();
+  let mut simulator = steering(&log);
+
+  simulator.push_event(controller_button_down(0, Button::DPadUp));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(Event::ControllerDeviceRemoved { timestamp: 0, which: 0 });
+  simulator.dispatch_events();
+  simulator.tick();
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:false just_pressed:false just_released:true",
+    ]
+  );
+}
+
+#[test]
+fn unplugging_a_controller_keeps_the_bindings_held_by_others() {
+  let log = Log::default();
+  let mut simulator = steering(&log);
+
+  simulator.push_event(controller_button_down(0, Button::DPadUp));
+  simulator.push_event(controller_axis_motion(1, Axis::LeftY, i16::MIN));
+  simulator.push_event(controller_button_down(1, Button::DPadUp));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(Event::ControllerDeviceRemoved { timestamp: 0, which: 0 });
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(Event::ControllerDeviceRemoved { timestamp: 0, which: 1 });
+  simulator.dispatch_events();
+  simulator.tick();
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:true just_pressed:false just_released:false",
+      "pressed:false just_pressed:false just_released:true",
+    ]
+  );
+}
+
+#[test]
+fn axis_bindings_round_trip_through_strings() {
+  let binding = Binding::ControllerAxis {
+    axis: Axis::LeftY,
+    is_positive: false,
+  };
+
+  assert_eq!(binding.to_string(), "axis:-lefty");
+  assert_eq!("axis:-lefty".parse(), Ok(binding));
+  assert!("axis:lefty".parse::<Binding>().is_err());
+  assert!("axis:+".parse::<Binding>().is_err());
+}
+
+// Plugs a game controller in and out through SDL, like a player would
+#[cfg(target_os = "linux")]
+#[test]
+fn virtual_controllers_are_hot_plugged_and_steer_actions() {
+  use sdl2::{
+    sys::{self, SDL_JoystickType},
+    EventPump,
+  };
+
+  fn pump(event_pump: &mut EventPump, simulator: &mut Simulator) {
+    for event in event_pump.poll_iter() {
+      simulator.push_event(event);
+    }
+
+    simulator.dispatch_events();
+  }
+
+  let sdl = sdl2::init().unwrap();
+  let mut event_pump = sdl.event_pump().unwrap();
+  let log = Log::default();
+  let mut simulator = steering(&log).with_controllers(sdl.game_controller().unwrap());
+
+  // Virtual game controllers map their buttons and axes in the order of SDL game controller buttons and axes
+  let device_index =
+    unsafe { sys::SDL_JoystickAttachVirtual(SDL_JoystickType::SDL_JOYSTICK_TYPE_GAMECONTROLLER, 6, 15, 0) };
+  assert!(device_index >= 0, "{}", sdl2::get_error());
+  let joystick = unsafe { sys::SDL_JoystickOpen(device_index) };
+  assert!(!joystick.is_null(), "{}", sdl2::get_error());
+
+  pump(&mut event_pump, &mut simulator);
+  assert_eq!(simulator.get_context().get_controllers().len(), 1);
+
+  unsafe { sys::SDL_JoystickSetVirtualButton(joystick, Button::DPadUp as _, 1) };
+  pump(&mut event_pump, &mut simulator);
+  simulator.tick();
+  assert!(simulator
+    .get_context()
+    .get_controllers()
+    .is_button_pressed(Button::DPadUp));
+
+  unsafe { sys::SDL_JoystickSetVirtualButton(joystick, Button::DPadUp as _, 0) };
+  pump(&mut event_pump, &mut simulator);
+  simulator.tick();
+
+  unsafe { sys::SDL_JoystickSetVirtualAxis(joystick, Axis::LeftY as _, i16::MIN) };
+  pump(&mut event_pump, &mut simulator);
+  simulator.tick();
+  assert_eq!(simulator.get_context().get_controllers().get_axis(Axis::LeftY), -1f32);
+
+  unsafe {
+    sys::SDL_JoystickClose(joystick);
+    sys::SDL_JoystickDetachVirtual(device_index);
+  }
+  pump(&mut event_pump, &mut simulator);
+  simulator.tick();
+  assert!(simulator.get_context().get_controllers().is_empty());
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:false just_pressed:false just_released:true",
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:false just_pressed:false just_released:true",
+    ]
+  );
+}
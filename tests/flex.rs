@@ -0,0 +1,97 @@
+use skia_test::{
+  layouts::{CrossAxisAlignment, Flex, FlexChild, MainAxisAlignment},
+  models::Axis,
+  testing::{probe, rect, Simulator},
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode},
+};
+use std::rc::Rc;
+
+#[test]
+fn row_places_children_with_spacing() {
+  let (first, first_rect) = probe((10f32, 20f32));
+  let (second, second_rect) = probe((30f32, 40f32));
+
+  let row = Flex {
+    spacing: 4f32,
+    ..Flex::row(vec![first.into_view().into(), second.into_view().into()])
+  };
+
+  Simulator::new(row.into_view(), (100, 50)).draw();
+
+  assert_eq!(first_rect.get(), rect((0f32, 0f32), (10f32, 20f32)));
+  assert_eq!(second_rect.get(), rect((14f32, 0f32), (30f32, 40f32)));
+}
+
+#[test]
+fn column_aligns_children_along_both_axes() {
+  let (first, first_rect) = probe((10f32, 20f32));
+  let (second, second_rect) = probe((30f32, 40f32));
+
+  let column = Flex {
+    main_axis_alignment: MainAxisAlignment::Center,
+    cross_axis_alignment: CrossAxisAlignment::End,
+    ..Flex::column(vec![first.into_view().into(), second.into_view().into()])
+  };
+
+  Simulator::new(column.into_view(), (50, 100)).draw();
+
+  assert_eq!(first_rect.get(), rect((40f32, 20f32), (10f32, 20f32)));
+  assert_eq!(second_rect.get(), rect((20f32, 40f32), (30f32, 40f32)));
+}
+
+#[test]
+fn space_between_distributes_free_space() {
+  let probes = [0; 3].map(|_| probe((10f32, 10f32)));
+  let rects = probes.iter().map(|(_, rect)| Rc::clone(rect)).collect::<Vec<_>>();
+
+  let row = Flex {
+    main_axis_alignment: MainAxisAlignment::SpaceBetween,
+    ..Flex::row(probes.into_iter().map(|(probe, _)| probe.into_view().into()).collect())
+  };
+
+  Simulator::new(row.into_view(), (100, 10)).draw();
+
+  let xs = rects.iter().map(|rect| rect.get().position.0).collect::<Vec<_>>();
+  assert_eq!(xs, vec![0f32, 45f32, 90f32]);
+}
+
+#[test]
+fn flexible_children_share_space_left_by_weight() {
+  let (fixed, fixed_rect) = probe((20f32, 10f32));
+  let (one, one_rect) = probe((0f32, 10f32));
+  let (three, three_rect) = probe((0f32, 10f32));
+
+  let flex = Flex {
+    axis: Axis::Horizontal,
+    cross_axis_alignment: CrossAxisAlignment::Stretch,
+    children: vec![
+      fixed.into_view().into(),
+      FlexChild::flexible(1f32, one.into_view()),
+      FlexChild::flexible(3f32, three.into_view()),
+    ],
+    ..Default::default()
+  };
+
+  Simulator::new(flex.into_view(), (100, 30)).draw();
+
+  assert_eq!(fixed_rect.get(), rect((0f32, 0f32), (20f32, 30f32)));
+  assert_eq!(one_rect.get(), rect((20f32, 0f32), (20f32, 30f32)));
+  assert_eq!(three_rect.get(), rect((40f32, 0f32), (60f32, 30f32)));
+}
+
+#[test]
+fn overflowing_children_keep_their_size() {
+  let (first, first_rect) = probe((40f32, 10f32));
+  let (second, second_rect) = probe((40f32, 10f32));
+
+  let row = Flex {
+    main_axis_alignment: MainAxisAlignment::SpaceEvenly,
+    spacing: 10f32,
+    ..Flex::row(vec![first.into_view().into(), second.into_view().into()])
+  };
+
+  Simulator::new(row.into_view(), (64, 10)).draw();
+
+  assert_eq!(first_rect.get(), rect((0f32, 0f32), (40f32, 10f32)));
+  assert_eq!(second_rect.get(), rect((50f32, 0f32), (40f32, 10f32)));
+}
@@ -0,0 +1,273 @@
+use sdl2::{
+  event::Event,
+  keyboard::{Keycode, Mod},
+};
+use skia_test::{
+  common::Sharable,
+  layouts::{Focus, FocusScope, Stack, StackChild},
+  models::Alignment,
+  nodes::Node,
+  testing::{
+    events::{key_down_with_mod, mouse_button_down},
+    take, Log, Probe, Simulator,
+  },
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout},
+  View,
+};
+use std::rc::Rc;
+
+struct Square;
+
+impl Node for Square {
+  fn get_size(&self) -> (f32, f32) {
+    (20f32, 20f32)
+  }
+}
+
+fn focus(name: &'static str, log: &Log) -> Focus {
+  Focus {
+    on_focus_change: {
+      let log = Rc::clone(log);
+      Some(Box::new(move |_, has_focus| {
+        log
+          .borrow_mut()
+          .push(format!("{name}:{}", if has_focus { "gained" } else { "lost" }));
+      }))
+    },
+    on_key: {
+      let log = Rc::clone(log);
+      Some(Box::new(move |_, event| {
+        if let Event::KeyDown { .. } = event {
+          log.borrow_mut().push(format!("{name}:key"));
+        }
+      }))
+    },
+    child: Square.into_view(),
+    ..Default::default()
+  }
+}
+
+fn place(alignment: Alignment, child: Option<Sharable<View>>) -> StackChild {
+  StackChild {
+    alignment,
+    ..StackChild::new(child)
+  }
+}
+
+fn press_key(simulator: &mut Simulator, keycode: Keycode, keymod: Mod) {
+  simulator.push_event(key_down_with_mod(keycode, keymod));
+  simulator.dispatch_events();
+}
+
+// Three focusables at the top left, top right and bottom left of a 100x100 window
+fn corners(log: &Log) -> Simulator {
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![
+        place(Alignment::TOP_LEFT, focus("a", log).into_view()),
+        place(Alignment::TOP_RIGHT, focus("b", log).into_view()),
+        place(Alignment::BOTTOM_LEFT, focus("c", log).into_view()),
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+
+  // Resolve the focusables and their rects
+  simulator.draw();
+  simulator
+}
+
+#[test]
+fn tab_moves_focus_in_the_order_of_the_view_tree() {
+  let log = Log::default();
+  let mut simulator = corners(&log);
+
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  assert_eq!(take(&log), ["a:gained"]);
+
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  assert_eq!(
+    take(&log),
+    ["a:key", "a:lost", "b:gained", "b:key", "b:lost", "c:gained"]
+  );
+
+  // Wraps around both ways
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Tab, Mod::LSHIFTMOD);
+  assert_eq!(
+    take(&log),
+    ["c:key", "c:lost", "a:gained", "a:key", "a:lost", "c:gained"]
+  );
+}
+
+#[test]
+fn arrow_keys_move_focus_to_the_nearest_view_in_their_direction() {
+  let log = Log::default();
+  let mut simulator = corners(&log);
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  take(&log);
+
+  press_key(&mut simulator, Keycode::Right, Mod::NOMOD);
+  assert_eq!(take(&log), ["a:key", "a:lost", "b:gained"]);
+
+  // Nothing further right
+  press_key(&mut simulator, Keycode::Right, Mod::NOMOD);
+  assert_eq!(take(&log), ["b:key"]);
+
+  press_key(&mut simulator, Keycode::Left, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Down, Mod::NOMOD);
+  assert_eq!(
+    take(&log),
+    ["b:key", "b:lost", "a:gained", "a:key", "a:lost", "c:gained"]
+  );
+}
+
+#[test]
+fn key_events_reach_the_focused_view_before_the_rest_of_the_view_tree() {
+  let log = Log::default();
+
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![
+        Probe {
+          name: "background",
+          size: (f32::MAX, f32::MAX),
+          log: Rc::clone(&log),
+          ..Default::default()
+        }
+        .into_view()
+        .into(),
+        place(
+          Alignment::CENTER,
+          Focus {
+            child: Probe {
+              name: "inner",
+              size: (20f32, 20f32),
+              log: Rc::clone(&log),
+              ..Default::default()
+            }
+            .into_view(),
+            ..focus("a", &log)
+          }
+          .into_view(),
+        ),
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+  simulator.draw();
+
+  press_key(&mut simulator, Keycode::Space, Mod::NOMOD);
+  assert_eq!(take(&log), ["background:key", "inner:key"]);
+
+  // Pressing a focusable focuses it
+  simulator.push_event(mouse_button_down(50, 50));
+  simulator.dispatch_events();
+  assert_eq!(take(&log), ["inner:down", "a:gained"]);
+
+  // Descendants of the focused view receive them too
+  press_key(&mut simulator, Keycode::Space, Mod::NOMOD);
+  assert_eq!(take(&log), ["inner:key", "a:key", "background:key"]);
+}
+
+#[test]
+fn handled_keys_neither_reach_other_views_nor_move_focus() {
+  let log = Log::default();
+
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![
+        Probe {
+          name: "background",
+          size: (f32::MAX, f32::MAX),
+          log: Rc::clone(&log),
+          ..Default::default()
+        }
+        .into_view()
+        .into(),
+        place(
+          Alignment::TOP_LEFT,
+          Focus {
+            autofocus: true,
+            on_key: Some(Box::new(|context, _| context.stop_propagation())),
+            ..focus("a", &log)
+          }
+          .into_view(),
+        ),
+        place(Alignment::TOP_RIGHT, focus("b", &log).into_view()),
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+  simulator.draw();
+  assert_eq!(take(&log), ["a:gained"]);
+
+  press_key(&mut simulator, Keycode::Right, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  assert!(take(&log).is_empty());
+}
+
+#[test]
+fn focus_traversal_stays_in_the_focus_scope() {
+  let log = Log::default();
+
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![
+        place(Alignment::TOP_LEFT, focus("game", &log).into_view()),
+        place(
+          Alignment::BOTTOM_RIGHT,
+          FocusScope {
+            child: Stack {
+              children: vec![
+                place(
+                  Alignment::TOP_LEFT,
+                  Focus {
+                    autofocus: true,
+                    ..focus("resume", &log)
+                  }
+                  .into_view(),
+                ),
+                place(Alignment::BOTTOM_LEFT, focus("quit", &log).into_view()),
+              ],
+              ..Default::default()
+            }
+            .into_view(),
+          }
+          .into_view(),
+        ),
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+  simulator.draw();
+  take(&log);
+
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Tab, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Up, Mod::NOMOD);
+  press_key(&mut simulator, Keycode::Left, Mod::NOMOD);
+
+  assert_eq!(
+    take(&log),
+    [
+      "resume:key",
+      "resume:lost",
+      "quit:gained",
+      "quit:key",
+      "quit:lost",
+      "resume:gained",
+      "resume:key",
+      "resume:key",
+    ]
+  );
+}
@@ -0,0 +1,277 @@
+use sdl2::event::Event;
+use skia_test::{
+  layouts::{Drag, GestureDetector, GestureThresholds},
+  models::Direction,
+  nodes::BoxNode,
+  testing::{
+    events::{finger_down, finger_motion, finger_up, mouse_button_down, mouse_button_up, mouse_motion},
+    Simulator,
+  },
+  view::{IntoViewFromNode, IntoViewFromStatefulLayout},
+  Context,
+};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Debug, PartialEq)]
+enum Gesture {
+  Tap((f32, f32)),
+  DoubleTap((f32, f32)),
+  LongPress((f32, f32)),
+  DragStart(Drag),
+  DragUpdate(Drag),
+  DragEnd(Drag),
+  Swipe(Direction),
+}
+
+type Gestures = Rc<RefCell<Vec<Gesture>>>;
+
+type Callback<T> = Option<Box<dyn FnMut(&mut Context, T)>>;
+
+fn record<T: 'static>(gestures: &Gestures, gesture: fn(T) -> Gesture) -> Callback<T> {
+  let gestures = Rc::clone(gestures);
+  Some(Box::new(move |_, value| gestures.borrow_mut().push(gesture(value))))
+}
+
+fn detect(thresholds: GestureThresholds) -> (Simulator, Gestures) {
+  let gestures = Gestures::default();
+
+  let mut simulator = Simulator::new(
+    GestureDetector {
+      thresholds,
+      on_tap: record(&gestures, Gesture::Tap),
+      on_double_tap: record(&gestures, Gesture::DoubleTap),
+      on_long_press: record(&gestures, Gesture::LongPress),
+      on_drag_start: record(&gestures, Gesture::DragStart),
+      on_drag_update: record(&gestures, Gesture::DragUpdate),
+      on_drag_end: record(&gestures, Gesture::DragEnd),
+      on_swipe: record(&gestures, Gesture::Swipe),
+      child: BoxNode::default().into_view(),
+    }
+    .into_view(),
+    (200, 200),
+  );
+
+  // Resolve the rect which events are hit tested against
+  simulator.draw();
+
+  (simulator, gestures)
+}
+
+fn press(simulator: &mut Simulator, x: i32, y: i32) {
+  simulator.push_event(mouse_button_down(x, y));
+  simulator.dispatch_events();
+}
+
+fn move_to(simulator: &mut Simulator, x: i32, y: i32) {
+  simulator.push_event(mouse_motion(x, y, (0, 0)));
+  simulator.dispatch_events();
+}
+
+fn release(simulator: &mut Simulator, x: i32, y: i32) {
+  simulator.push_event(mouse_button_up(x, y));
+  simulator.dispatch_events();
+}
+
+fn touch(simulator: &mut Simulator, event: fn(f32, f32) -> Event, x: f32, y: f32) {
+  // Touch positions are normalized to the window
+  simulator.push_event(event(x / 200f32, y / 200f32));
+  simulator.dispatch_events();
+}
+
+#[test]
+fn taps_within_the_slop_are_taps() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+  press(&mut simulator, 50, 50);
+  move_to(&mut simulator, 53, 54);
+  release(&mut simulator, 53, 54);
+
+  assert_eq!(*gestures.borrow(), [Gesture::Tap((53f32, 54f32))]);
+}
+
+#[test]
+fn second_tap_within_the_interval_is_a_double_tap() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+  press(&mut simulator, 50, 50);
+  release(&mut simulator, 50, 50);
+  simulator.advance(12);
+  press(&mut simulator, 50, 50);
+  release(&mut simulator, 50, 50);
+
+  // Too late for another double tap
+  simulator.advance(60);
+  press(&mut simulator, 50, 50);
+  release(&mut simulator, 50, 50);
+
+  assert_eq!(
+    *gestures.borrow(),
+    [
+      Gesture::Tap((50f32, 50f32)),
+      Gesture::DoubleTap((50f32, 50f32)),
+      Gesture::Tap((50f32, 50f32)),
+    ]
+  );
+}
+
+#[test]
+fn holding_still_is_a_long_press_instead_of_a_tap() {
+  let (mut simulator, gestures) = detect(GestureThresholds {
+    long_press_duration: 0.25f32,
+    ..Default::default()
+  });
+  press(&mut simulator, 50, 50);
+  simulator.advance(29);
+
+  assert!(gestures.borrow().is_empty());
+
+  simulator.advance(1);
+  release(&mut simulator, 50, 50);
+
+  assert_eq!(*gestures.borrow(), [Gesture::LongPress((50f32, 50f32))]);
+}
+
+#[test]
+fn moving_past_the_slop_drags_with_delta_and_velocity() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+  press(&mut simulator, 50, 50);
+  move_to(&mut simulator, 60, 50);
+  simulator.tick();
+  move_to(&mut simulator, 62, 51);
+  release(&mut simulator, 62, 51);
+
+  let gestures = gestures.borrow();
+
+  assert_eq!(
+    gestures[..2],
+    [
+      Gesture::DragStart(Drag {
+        position: (50f32, 50f32),
+        ..Default::default()
+      }),
+      Gesture::DragUpdate(Drag {
+        position: (60f32, 50f32),
+        delta: (10f32, 0f32),
+        velocity: (0f32, 0f32),
+      }),
+    ]
+  );
+
+  let Gesture::DragUpdate(update) = gestures[2] else {
+    panic!("drag must update on motion");
+  };
+
+  assert_eq!(update.position, (62f32, 51f32));
+  assert_eq!(update.delta, (2f32, 1f32));
+
+  // Moved 12 pixels right and 1 pixel down since the press a tick before
+  let Gesture::DragEnd(end) = gestures[3] else {
+    panic!("drag must end on release");
+  };
+
+  assert_eq!(end.position, (62f32, 51f32));
+  assert!((end.velocity.0 - 1440f32).abs() < 0.01f32);
+  assert!((end.velocity.1 - 120f32).abs() < 0.01f32);
+}
+
+#[test]
+fn fast_drags_are_swipes_along_their_main_axis() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+
+  let mut swipe = |from: (i32, i32), to: (i32, i32)| {
+    press(&mut simulator, from.0, from.1);
+    move_to(&mut simulator, to.0, to.1);
+    simulator.tick();
+    release(&mut simulator, to.0, to.1);
+
+    gestures.borrow_mut().pop()
+  };
+
+  assert_eq!(swipe((100, 100), (100, 40)), Some(Gesture::Swipe(Direction::Up)));
+  assert_eq!(swipe((100, 100), (160, 110)), Some(Gesture::Swipe(Direction::Right)));
+  assert_eq!(swipe((100, 100), (90, 150)), Some(Gesture::Swipe(Direction::Down)));
+  assert_eq!(swipe((100, 100), (30, 100)), Some(Gesture::Swipe(Direction::Left)));
+
+  // Too short
+  assert!(matches!(swipe((100, 100), (120, 100)), Some(Gesture::DragEnd(_))));
+}
+
+#[test]
+fn swipes_are_detected_across_frames_of_several_ticks() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+
+  // Events only arrive between frames like in app::run(), where each frame runs 2 ticks
+  press(&mut simulator, 100, 100);
+  simulator.advance_frame(1f32 / 60f32);
+  move_to(&mut simulator, 100, 130);
+  simulator.advance_frame(1f32 / 60f32);
+  move_to(&mut simulator, 100, 160);
+  simulator.advance_frame(1f32 / 60f32);
+  release(&mut simulator, 100, 160);
+
+  assert_eq!(gestures.borrow().last(), Some(&Gesture::Swipe(Direction::Down)));
+}
+
+#[test]
+fn drags_resting_before_release_are_not_swipes() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+  press(&mut simulator, 100, 100);
+  simulator.advance_frame(1f32 / 60f32);
+  move_to(&mut simulator, 160, 100);
+
+  for _ in 0..10 {
+    simulator.advance_frame(1f32 / 60f32);
+  }
+
+  release(&mut simulator, 160, 100);
+
+  let gestures = gestures.borrow();
+  let Some(Gesture::DragEnd(end)) = gestures.last() else {
+    panic!("drag must end on release");
+  };
+
+  assert_eq!(end.velocity, (0f32, 0f32));
+}
+
+#[test]
+fn slow_drags_are_not_swipes() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+  press(&mut simulator, 100, 100);
+
+  for x in 101..=160 {
+    move_to(&mut simulator, x, 100);
+    simulator.tick();
+  }
+
+  release(&mut simulator, 160, 100);
+
+  assert!(matches!(gestures.borrow().last(), Some(Gesture::DragEnd(_))));
+}
+
+#[test]
+fn thresholds_are_configurable() {
+  let (mut simulator, gestures) = detect(GestureThresholds {
+    slop: 30f32,
+    ..Default::default()
+  });
+  press(&mut simulator, 50, 50);
+  move_to(&mut simulator, 70, 50);
+  release(&mut simulator, 70, 50);
+
+  assert_eq!(*gestures.borrow(), [Gesture::Tap((70f32, 50f32))]);
+}
+
+#[test]
+fn fingers_make_gestures_too() {
+  let (mut simulator, gestures) = detect(GestureThresholds::default());
+
+  touch(&mut simulator, finger_down, 100f32, 100f32);
+  touch(&mut simulator, finger_up, 100f32, 100f32);
+
+  touch(&mut simulator, finger_down, 100f32, 100f32);
+  touch(&mut simulator, finger_motion, 100f32, 150f32);
+  simulator.tick();
+  touch(&mut simulator, finger_up, 100f32, 150f32);
+
+  let gestures = gestures.borrow();
+  assert_eq!(gestures.first(), Some(&Gesture::Tap((100f32, 100f32))));
+  assert_eq!(gestures.last(), Some(&Gesture::Swipe(Direction::Down)));
+}
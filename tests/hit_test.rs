@@ -0,0 +1,409 @@
+use sdl2::{event::Event, keyboard::Keycode};
+use skia_test::{
+  common::Sharable,
+  layouts::{MultiChildLayout, Stack, StackChild, Transform},
+  models::{Alignment, Box2D},
+  nodes::GridNode,
+  testing::{
+    events::{key_down, mouse_button_down, mouse_button_up, mouse_motion},
+    get_event_kind, take, Log, Probe, Simulator,
+  },
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatelessLayout},
+  Context, View,
+};
+use std::{cell::RefCell, rc::Rc};
+
+// Logs the capture and bubble phases of each event dispatched through it
+struct Layer {
+  name: &'static str,
+  is_stopping_on_capture: bool,
+  log: Log,
+  child: Option<Rc<RefCell<View>>>,
+}
+
+impl MultiChildLayout for Layer {
+  fn on_capture_event(&mut self, context: &mut Context, event: &Event) {
+    self
+      .log
+      .borrow_mut()
+      .push(format!("{}:capture {}", self.name, get_event_kind(event)));
+
+    if self.is_stopping_on_capture {
+      context.stop_propagation();
+    }
+  }
+
+  fn on_event(&mut self, _context: &mut Context, event: &Event) {
+    self
+      .log
+      .borrow_mut()
+      .push(format!("{}:bubble {}", self.name, get_event_kind(event)));
+  }
+
+  fn make(&self, _constraint: Box2D) -> Vec<Sharable<View>> {
+    self.child.iter().map(|child| child.into()).collect()
+  }
+}
+
+fn layer(name: &'static str, log: &Log, child: Option<Sharable<View>>) -> Layer {
+  Layer {
+    name,
+    is_stopping_on_capture: false,
+    log: Rc::clone(log),
+    child: child.map(Sharable::into_shared),
+  }
+}
+
+// A full size background with a 20x20 panel centered on top in a 100x100 window
+fn panel_over_background(log: &Log) -> Simulator {
+  Simulator::new(
+    Stack {
+      children: vec![
+        Probe {
+          name: "background",
+          size: (f32::MAX, f32::MAX),
+          log: Rc::clone(log),
+          ..Default::default()
+        }
+        .into_view()
+        .into(),
+        StackChild {
+          alignment: Alignment::CENTER,
+          ..StackChild::new(
+            Probe {
+              name: "panel",
+              size: (20f32, 20f32),
+              log: Rc::clone(log),
+              ..Default::default()
+            }
+            .into_view(),
+          )
+        },
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  )
+}
+
+fn push_button(simulator: &mut Simulator, x: i32, y: i32, is_down: bool) {
+  // Resolve the rects which events are hit tested against
+  simulator.draw();
+
+  simulator.push_event(if is_down {
+    mouse_button_down(x, y)
+  } else {
+    mouse_button_up(x, y)
+  });
+  simulator.dispatch_events();
+}
+
+fn move_mouse(simulator: &mut Simulator, x: i32, y: i32) {
+  simulator.draw();
+
+  simulator.push_event(mouse_motion(x, y, (0, 0)));
+  simulator.dispatch_events();
+}
+
+fn press_key(simulator: &mut Simulator) {
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.dispatch_events();
+}
+
+#[test]
+fn pointer_events_only_reach_the_topmost_view_under_the_pointer() {
+  let log = Log::default();
+  let mut simulator = panel_over_background(&log);
+
+  push_button(&mut simulator, 50, 50, true);
+  assert_eq!(take(&log), ["panel:down"]);
+
+  push_button(&mut simulator, 50, 50, false);
+  push_button(&mut simulator, 5, 5, true);
+  assert_eq!(take(&log), ["panel:up", "background:down"]);
+}
+
+#[test]
+fn other_events_reach_every_view() {
+  let log = Log::default();
+  let mut simulator = panel_over_background(&log);
+  press_key(&mut simulator);
+
+  assert_eq!(take(&log), ["background:key", "panel:key"]);
+}
+
+#[test]
+fn events_are_captured_down_the_tree_and_bubble_back_up() {
+  let log = Log::default();
+  let inner = layer(
+    "inner",
+    &log,
+    Probe {
+      log: Rc::clone(&log),
+      ..Default::default()
+    }
+    .into_view(),
+  );
+
+  let mut simulator = Simulator::new(layer("outer", &log, inner.into_view()).into_view(), (100, 100));
+  push_button(&mut simulator, 50, 50, true);
+
+  assert_eq!(
+    take(&log),
+    [
+      "outer:capture down",
+      "inner:capture down",
+      "probe:down",
+      "inner:bubble down",
+      "outer:bubble down",
+    ]
+  );
+}
+
+#[test]
+fn stopping_propagation_keeps_the_event_from_other_views() {
+  let log = Log::default();
+  let inner = Layer {
+    is_stopping_on_capture: true,
+    ..layer(
+      "inner",
+      &log,
+      Probe {
+        log: Rc::clone(&log),
+        ..Default::default()
+      }
+      .into_view(),
+    )
+  };
+
+  let mut simulator = Simulator::new(layer("outer", &log, inner.into_view()).into_view(), (100, 100));
+  push_button(&mut simulator, 50, 50, true);
+
+  assert_eq!(take(&log), ["outer:capture down", "inner:capture down"]);
+
+  let probe = Probe {
+    is_stopping: true,
+    log: Rc::clone(&log),
+    ..Default::default()
+  };
+
+  let mut simulator = Simulator::new(layer("outer", &log, probe.into_view()).into_view(), (100, 100));
+  press_key(&mut simulator);
+
+  assert_eq!(take(&log), ["outer:capture key", "probe:key"]);
+}
+
+#[test]
+fn pointer_enters_hovers_and_leaves_views() {
+  let log = Log::default();
+  let mut simulator = panel_over_background(&log);
+
+  move_mouse(&mut simulator, 5, 5);
+  assert_eq!(
+    take(&log),
+    ["background:enter", "background:hover(5.0, 5.0)", "background:motion"]
+  );
+
+  // The panel covers the background
+  move_mouse(&mut simulator, 50, 50);
+  assert_eq!(
+    take(&log),
+    [
+      "panel:enter",
+      "panel:hover(50.0, 50.0)",
+      "panel:motion",
+      "background:leave"
+    ]
+  );
+
+  move_mouse(&mut simulator, 55, 50);
+  assert_eq!(take(&log), ["panel:hover(55.0, 50.0)", "panel:motion"]);
+
+  move_mouse(&mut simulator, 5, 5);
+  assert_eq!(
+    take(&log),
+    [
+      "panel:leave",
+      "background:enter",
+      "background:hover(5.0, 5.0)",
+      "background:motion",
+    ]
+  );
+}
+
+#[test]
+fn pressed_views_receive_pointer_events_until_released() {
+  let log = Log::default();
+  let mut simulator = panel_over_background(&log);
+  push_button(&mut simulator, 50, 50, true);
+  take(&log);
+
+  // Dragged off the panel
+  move_mouse(&mut simulator, 5, 5);
+  assert!(take(&log).contains(&String::from("panel:motion")));
+
+  push_button(&mut simulator, 5, 5, false);
+  assert!(take(&log).contains(&String::from("panel:up")));
+
+  move_mouse(&mut simulator, 6, 6);
+  assert!(!take(&log).contains(&String::from("panel:motion")));
+}
+
+#[test]
+fn pointer_events_are_hit_tested_through_transforms() {
+  let log = Log::default();
+
+  let mut simulator = Simulator::new(
+    Transform {
+      origin: Alignment::TOP_LEFT,
+      translation: (60f32, 0f32),
+      ..Transform::new(
+        Stack {
+          children: vec![StackChild {
+            alignment: Alignment::TOP_LEFT,
+            ..StackChild::new(
+              Probe {
+                name: "panel",
+                size: (20f32, 20f32),
+                log: Rc::clone(&log),
+                ..Default::default()
+              }
+              .into_view(),
+            )
+          }],
+          ..Default::default()
+        }
+        .into_view(),
+      )
+    }
+    .into_view(),
+    (100, 100),
+  );
+
+  // Where the panel would be without the transform
+  push_button(&mut simulator, 10, 10, true);
+  assert!(take(&log).is_empty());
+
+  push_button(&mut simulator, 70, 10, true);
+  assert_eq!(take(&log), ["panel:down"]);
+}
+
+#[test]
+fn transformed_views_are_hit_where_they_are_drawn() {
+  let log = Log::default();
+
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![StackChild {
+        alignment: Alignment::TOP_LEFT,
+        ..StackChild::new(
+          Transform {
+            origin: Alignment::TOP_LEFT,
+            translation: (60f32, 0f32),
+            ..Transform::new(
+              Probe {
+                name: "panel",
+                size: (20f32, 20f32),
+                log: Rc::clone(&log),
+                ..Default::default()
+              }
+              .into_view(),
+            )
+          }
+          .into_view(),
+        )
+      }],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+
+  // Within the layout rect of the transform, which the panel is moved out of
+  push_button(&mut simulator, 10, 10, true);
+  push_button(&mut simulator, 10, 10, false);
+  assert!(take(&log).is_empty());
+
+  push_button(&mut simulator, 70, 10, true);
+  assert_eq!(take(&log), ["panel:down"]);
+}
+
+#[test]
+fn pressed_views_off_the_pointer_cannot_stop_propagation() {
+  let log = Log::default();
+
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![
+        Probe {
+          name: "background",
+          size: (f32::MAX, f32::MAX),
+          log: Rc::clone(&log),
+          ..Default::default()
+        }
+        .into_view()
+        .into(),
+        StackChild {
+          alignment: Alignment::CENTER,
+          ..StackChild::new(
+            Probe {
+              name: "panel",
+              size: (20f32, 20f32),
+              is_stopping: true,
+              log: Rc::clone(&log),
+              ..Default::default()
+            }
+            .into_view(),
+          )
+        },
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+
+  push_button(&mut simulator, 50, 50, true);
+  take(&log);
+
+  // Dragged off the panel onto the background
+  move_mouse(&mut simulator, 5, 5);
+  let log = take(&log);
+  assert!(log.contains(&String::from("panel:motion")));
+  assert!(log.contains(&String::from("background:motion")));
+}
+
+#[test]
+fn grid_cells_only_receive_pointer_events_under_the_pointer() {
+  let log = Log::default();
+  let maker_log = Rc::clone(&log);
+
+  let mut simulator = Simulator::new(
+    GridNode {
+      dim: (2, 2),
+      gap: (10f32, 10f32),
+      maker: Box::new(move |i| {
+        Box::new(Probe {
+          name: ["cell0", "cell1", "cell2", "cell3"][i],
+          size: (f32::MAX, f32::MAX),
+          log: Rc::clone(&maker_log),
+          ..Default::default()
+        })
+      }),
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+
+  push_button(&mut simulator, 70, 70, true);
+  assert_eq!(take(&log), ["cell3:down"]);
+
+  // In the gap between cells
+  push_button(&mut simulator, 50, 50, true);
+  assert!(take(&log).is_empty());
+
+  press_key(&mut simulator);
+  assert_eq!(take(&log), ["cell0:key", "cell1:key", "cell2:key", "cell3:key"]);
+}
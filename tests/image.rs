@@ -0,0 +1,154 @@
+use skia_safe::{surfaces, Color, IRect, Image, Paint, Rect};
+use skia_test::{
+  models::Constraints,
+  nodes::{ImageFit, ImageNode, Node},
+  testing::{get_pixel, Simulator},
+  view::IntoViewFromNode,
+};
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+// Paints the given rects of an image of the given size in the given colors over a blue background
+fn make_image(size: (i32, i32), rects: &[(Rect, Color)]) -> Image {
+  let mut surface = surfaces::raster_n32_premul(size).unwrap();
+  let canvas = surface.canvas();
+  canvas.clear(Color::BLUE);
+
+  for &(rect, color) in rects {
+    let mut paint = Paint::default();
+    paint.set_color(color);
+    canvas.draw_rect(rect, &paint);
+  }
+
+  surface.image_snapshot()
+}
+
+// A column of a red, a green and a blue pixel
+fn make_column() -> Image {
+  make_image(
+    (1, 3),
+    &[
+      (Rect::from_xywh(0f32, 0f32, 1f32, 1f32), Color::RED),
+      (Rect::from_xywh(0f32, 1f32, 1f32, 1f32), Color::GREEN),
+    ],
+  )
+}
+
+// Draws the given image node over a black background and returns the colors of the pixels at the given positions
+fn draw(image: ImageNode, size: (u32, u32), positions: &[(i32, i32)]) -> Vec<[u8; 4]> {
+  let mut simulator = Simulator::new(image.into_view(), size);
+  let image = simulator.draw();
+
+  positions.iter().map(|&(x, y)| get_pixel(image, x, y)).collect()
+}
+
+#[test]
+fn images_are_loaded_from_assets() {
+  let mut simulator = Simulator::new(None, (64, 64)).with_images();
+  let image = simulator.get_context().get_image("favicon").unwrap();
+
+  assert_eq!((image.width(), image.height()), (64, 64));
+}
+
+#[test]
+fn image_shrinks_to_fit_in_constraints() {
+  let image = ImageNode {
+    image: Simulator::new(None, (64, 64))
+      .with_images()
+      .get_context()
+      .get_image("favicon"),
+    ..Default::default()
+  };
+
+  assert_eq!(image.measure(Constraints::loose((100f32, 100f32))), (64f32, 64f32));
+  assert_eq!(image.measure(Constraints::loose((32f32, 100f32))), (32f32, 32f32));
+}
+
+#[test]
+fn missing_image_takes_no_space() {
+  let image = ImageNode::default();
+
+  assert_eq!(image.measure(Constraints::loose((100f32, 100f32))), (0f32, 0f32));
+}
+
+#[test]
+fn fill_stretches_the_image_to_the_rect() {
+  let image = ImageNode {
+    image: Some(make_column()),
+    fit: ImageFit::Fill,
+    ..Default::default()
+  };
+
+  assert_eq!(
+    draw(image, (4, 3), &[(0, 0), (3, 0), (0, 1), (3, 2)]),
+    [RED, RED, GREEN, BLUE]
+  );
+}
+
+#[test]
+fn contain_fits_the_whole_image_in_the_center() {
+  let image = ImageNode {
+    image: Some(make_column()),
+    fit: ImageFit::Contain,
+    ..Default::default()
+  };
+
+  assert_eq!(
+    draw(image, (5, 3), &[(2, 0), (2, 1), (2, 2), (1, 1), (3, 1)]),
+    [RED, GREEN, BLUE, BLACK, BLACK]
+  );
+}
+
+#[test]
+fn cover_crops_the_image_to_the_rect() {
+  let image = ImageNode {
+    image: Some(make_column()),
+    fit: ImageFit::Cover,
+    ..Default::default()
+  };
+
+  // Scaled 4 times, which leaves room for only the center of the green pixel
+  assert_eq!(
+    draw(image, (4, 3), &[(0, 0), (3, 0), (0, 2), (3, 2)]),
+    [GREEN, GREEN, GREEN, GREEN]
+  );
+}
+
+#[test]
+fn none_keeps_the_original_size_in_the_center() {
+  let image = ImageNode {
+    image: Some(make_column()),
+    fit: ImageFit::None,
+    ..Default::default()
+  };
+
+  assert_eq!(draw(image, (5, 1), &[(2, 0), (1, 0), (3, 0)]), [GREEN, BLACK, BLACK]);
+}
+
+#[test]
+fn nine_slice_keeps_the_corners() {
+  // Red corners, green edges and a blue center of a pixel each
+  let corners = [(0f32, 0f32), (2f32, 0f32), (0f32, 2f32), (2f32, 2f32)]
+    .map(|(x, y)| (Rect::from_xywh(x, y, 1f32, 1f32), Color::RED));
+  let edges = [(1f32, 0f32), (0f32, 1f32), (2f32, 1f32), (1f32, 2f32)]
+    .map(|(x, y)| (Rect::from_xywh(x, y, 1f32, 1f32), Color::GREEN));
+
+  let image = ImageNode {
+    image: Some(make_image((3, 3), &[corners, edges].concat())),
+    nine_slice: Some(IRect::from_ltrb(1, 1, 2, 2)),
+    ..Default::default()
+  };
+
+  assert_eq!(
+    draw(image.clone(), (9, 9), &[(0, 0), (8, 0), (0, 8), (8, 8)]),
+    [RED, RED, RED, RED]
+  );
+  assert_eq!(
+    draw(image.clone(), (9, 9), &[(1, 0), (7, 0), (0, 4), (8, 4), (4, 8)]),
+    [GREEN, GREEN, GREEN, GREEN, GREEN]
+  );
+  assert_eq!(draw(image, (9, 9), &[(1, 1), (4, 4), (7, 7)]), [BLUE, BLUE, BLUE]);
+}
@@ -0,0 +1,179 @@
+use sdl2::{controller::Button, keyboard::Keycode, mouse::MouseButton};
+use skia_test::{
+  common::Sharable,
+  input::{Binding, InputMap},
+  layouts::{stateful_layout::State, StatefulLayout},
+  models::Box2D,
+  testing::{
+    events::{controller_button_down, key_down, key_repeat, key_up},
+    take, Log, Simulator,
+  },
+  view::IntoViewFromStatefulLayout,
+  Context, View,
+};
+use std::{cell::RefCell, env, fs, rc::Rc};
+
+// Logs the states of the jump action on each tick
+struct Player {
+  log: Log,
+}
+
+impl StatefulLayout for Player {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(PlayerState {
+      log: Rc::clone(&self.log),
+    }))
+  }
+}
+
+#[derive(Debug)]
+struct PlayerState {
+  log: Log,
+}
+
+impl State for PlayerState {
+  fn tick(&mut self, context: &mut Context, _dt: f32) {
+    self.log.borrow_mut().push(format!(
+      "pressed:{} just_pressed:{} just_released:{}",
+      context.is_action_pressed("jump"),
+      context.is_action_just_pressed("jump"),
+      context.is_action_just_released("jump")
+    ));
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    None
+  }
+}
+
+fn jump_map() -> InputMap {
+  InputMap::default().with_action(
+    "jump",
+    [
+      Binding::Key(Keycode::Space),
+      Binding::MouseButton(MouseButton::Left),
+      Binding::ControllerButton(Button::A),
+    ],
+  )
+}
+
+fn player(log: &Log) -> Simulator {
+  Simulator::new(Player { log: Rc::clone(log) }.into_view(), (100, 100)).with_input_map(jump_map())
+}
+
+#[test]
+fn actions_are_just_pressed_for_the_first_tick_after_being_pressed() {
+  let log = Log::default();
+  let mut simulator = player(&log);
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.dispatch_events();
+  simulator.advance(2);
+
+  // Key repeats are not presses
+  simulator.push_event(key_repeat(Keycode::Space));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(key_up(Keycode::Space));
+  simulator.dispatch_events();
+  simulator.advance(2);
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:true just_pressed:false just_released:false",
+      "pressed:true just_pressed:false just_released:false",
+      "pressed:false just_pressed:false just_released:true",
+      "pressed:false just_pressed:false just_released:false",
+    ]
+  );
+}
+
+#[test]
+fn actions_are_pressed_by_any_of_their_bindings() {
+  let log = Log::default();
+  let mut simulator = player(&log);
+
+  simulator.push_event(controller_button_down(0, Button::A));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  // Unbound inputs do nothing
+  simulator.push_event(controller_button_down(0, Button::B));
+  simulator.push_event(key_down(Keycode::Return));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:true just_pressed:true just_released:false",
+      "pressed:true just_pressed:false just_released:false",
+    ]
+  );
+}
+
+#[test]
+fn rebinding_takes_effect_immediately() {
+  let log = Log::default();
+  let mut simulator = player(&log);
+
+  let input_map = simulator.get_context().get_input_map_mut();
+  input_map.unbind("jump", Binding::Key(Keycode::Space));
+  input_map.bind("jump", Binding::Key(Keycode::W));
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  simulator.push_event(key_down(Keycode::W));
+  simulator.dispatch_events();
+  simulator.tick();
+
+  assert_eq!(
+    take(&log),
+    [
+      "pressed:false just_pressed:false just_released:false",
+      "pressed:true just_pressed:true just_released:false",
+    ]
+  );
+}
+
+#[test]
+fn bindings_round_trip_through_json() {
+  let input_map = jump_map().with_action("pause", [Binding::Key(Keycode::Escape)]);
+  let json = input_map.to_json();
+
+  assert!(json.contains(r#""key:Space""#));
+  assert!(json.contains(r#""mouse:Left""#));
+  assert!(json.contains(r#""button:a""#));
+  assert_eq!(InputMap::from_json(&json).unwrap(), input_map);
+}
+
+#[test]
+fn invalid_bindings_fail_to_parse() {
+  assert!("Space".parse::<Binding>().is_err());
+  assert!("pedal:Space".parse::<Binding>().is_err());
+  assert!("mouse:Wheel".parse::<Binding>().is_err());
+  assert_eq!("button:dpup".parse(), Ok(Binding::ControllerButton(Button::DPadUp)));
+  assert!(InputMap::from_json(r#"{ "jump": ["pedal:Space"] }"#).is_err());
+}
+
+#[test]
+fn loading_overrides_the_bindings_of_saved_actions_only() {
+  let path = env::temp_dir().join("skia_test_input_controls.json");
+  InputMap::default()
+    .with_action("jump", [Binding::Key(Keycode::W)])
+    .save(&path)
+    .unwrap();
+
+  let mut input_map = jump_map().with_action("pause", [Binding::Key(Keycode::Escape)]);
+  input_map.load(&path).unwrap();
+  fs::remove_file(&path).unwrap();
+
+  assert_eq!(input_map.get_bindings("jump"), [Binding::Key(Keycode::W)]);
+  assert_eq!(input_map.get_bindings("pause"), [Binding::Key(Keycode::Escape)]);
+  assert!(input_map.get_bindings("crouch").is_empty());
+}
@@ -0,0 +1,219 @@
+use sdl2::keyboard::Keycode;
+use skia_safe::Path;
+use skia_test::{
+  layouts::{Clip, ClipShape, ScrollView, Stack},
+  testing::{
+    events::{key_down, mouse_button_down, mouse_button_up, mouse_motion, mouse_wheel},
+    probe, take, Probe, Simulator,
+  },
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout, IntoViewFromStatelessLayout},
+};
+use std::{cell::Cell, rc::Rc};
+
+// A view of 100 x 100 pixels scrolling a child of 100 x 300 pixels
+fn simulate(child: Probe) -> (Simulator, Rc<Cell<f32>>) {
+  let offset = Rc::new(Cell::new(0f32));
+
+  let mut simulator = Simulator::new(
+    ScrollView {
+      offset: Rc::clone(&offset),
+      child: child.into_view(),
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+  simulator.draw();
+
+  (simulator, offset)
+}
+
+#[test]
+fn child_is_laid_out_at_its_full_size() {
+  let (child, rect) = probe((100f32, 300f32));
+  let (_, offset) = simulate(child);
+
+  assert_eq!(rect.get().size, (100f32, 300f32));
+  assert_eq!(offset.get(), 0f32);
+}
+
+#[test]
+fn wheel_scrolls_within_the_child() {
+  let (mut simulator, offset) = simulate(probe((100f32, 300f32)).0);
+
+  simulator.push_event(mouse_wheel(-1));
+  simulator.tick();
+  assert_eq!(offset.get(), 40f32);
+
+  simulator.push_event(mouse_wheel(-10));
+  simulator.tick();
+  assert_eq!(offset.get(), 200f32);
+
+  simulator.push_event(mouse_wheel(1));
+  simulator.tick();
+  assert_eq!(offset.get(), 160f32);
+}
+
+#[test]
+fn keys_scroll_by_step_page_and_to_both_ends() {
+  let (mut simulator, offset) = simulate(probe((100f32, 300f32)).0);
+
+  simulator.push_event(key_down(Keycode::Down));
+  simulator.tick();
+  assert_eq!(offset.get(), 40f32);
+
+  simulator.push_event(key_down(Keycode::PageDown));
+  simulator.tick();
+  assert_eq!(offset.get(), 140f32);
+
+  simulator.push_event(key_down(Keycode::End));
+  simulator.tick();
+  assert_eq!(offset.get(), 200f32);
+
+  simulator.push_event(key_down(Keycode::Up));
+  simulator.tick();
+  assert_eq!(offset.get(), 160f32);
+
+  simulator.push_event(key_down(Keycode::Home));
+  simulator.tick();
+  assert_eq!(offset.get(), 0f32);
+}
+
+#[test]
+fn keys_scrolling_the_view_do_not_reach_other_views() {
+  let (sibling, _) = probe((0f32, 0f32));
+  let log = Rc::clone(&sibling.log);
+  let offset = Rc::new(Cell::new(0f32));
+
+  let mut simulator = Simulator::new(
+    Stack {
+      children: vec![
+        ScrollView {
+          offset: Rc::clone(&offset),
+          child: probe((100f32, 300f32)).0.into_view(),
+          ..Default::default()
+        }
+        .into_view()
+        .into(),
+        sibling.into_view().into(),
+      ],
+      ..Default::default()
+    }
+    .into_view(),
+    (100, 100),
+  );
+  simulator.draw();
+
+  simulator.push_event(key_down(Keycode::Down));
+  simulator.tick();
+  assert_eq!(offset.get(), 40f32);
+  assert!(take(&log).is_empty());
+
+  // Nothing left to scroll
+  simulator.push_event(key_down(Keycode::Home));
+  simulator.push_event(key_down(Keycode::Home));
+  simulator.tick();
+  assert_eq!(offset.get(), 0f32);
+  assert_eq!(take(&log), ["probe:key"]);
+}
+
+#[test]
+fn child_smaller_than_the_view_does_not_scroll() {
+  let (mut simulator, offset) = simulate(probe((100f32, 50f32)).0);
+
+  simulator.push_event(key_down(Keycode::End));
+  simulator.tick();
+
+  assert_eq!(offset.get(), 0f32);
+}
+
+#[test]
+fn dragging_scrolls_and_keeps_scrolling_by_inertia() {
+  let (mut simulator, offset) = simulate(probe((100f32, 300f32)).0);
+
+  simulator.push_event(mouse_button_down(50, 80));
+  simulator.tick();
+  simulator.push_event(mouse_motion(50, 75, (0, -5)));
+  simulator.tick();
+  assert_eq!(offset.get(), 5f32);
+
+  simulator.push_event(mouse_button_up(50, 75));
+  simulator.advance(10);
+  let coasted = offset.get();
+  assert!(coasted > 5f32);
+
+  // Slows down until it stops
+  simulator.advance(600);
+  let stopped = offset.get();
+  simulator.advance(10);
+  assert!(stopped > coasted);
+  assert_eq!(offset.get(), stopped);
+}
+
+#[test]
+fn dragging_across_frames_of_several_ticks_keeps_its_velocity() {
+  let (mut simulator, offset) = simulate(probe((100f32, 300f32)).0);
+
+  // Each frame spans 4 ticks, so the ticks after the motion of a frame see no motion
+  simulator.push_event(mouse_button_down(50, 80));
+  simulator.advance_frame(1f32 / 30f32);
+  simulator.push_event(mouse_motion(50, 60, (0, -20)));
+  simulator.advance_frame(1f32 / 30f32);
+  assert_eq!(offset.get(), 20f32);
+
+  simulator.push_event(mouse_button_up(50, 60));
+  simulator.advance_frame(1f32 / 30f32);
+  assert!(offset.get() > 25f32);
+}
+
+#[test]
+fn pointer_positions_are_in_the_space_of_the_scrolled_child() {
+  let (child, _) = probe((100f32, 300f32));
+  let clicked_at = Rc::clone(&child.clicked_at);
+  let (mut simulator, offset) = simulate(child);
+
+  offset.set(120f32);
+  simulator.draw();
+  simulator.push_event(mouse_button_down(10, 30));
+  simulator.tick();
+
+  assert_eq!(clicked_at.get(), Some((10, 150)));
+}
+
+#[test]
+fn offset_set_by_the_parent_is_clamped() {
+  let (mut simulator, offset) = simulate(probe((100f32, 300f32)).0);
+
+  offset.set(1000f32);
+  simulator.push_event(key_down(Keycode::Up));
+  simulator.tick();
+
+  assert_eq!(offset.get(), 160f32);
+}
+
+#[test]
+fn clip_takes_the_size_of_its_child() {
+  for shape in [
+    ClipShape::Rect,
+    ClipShape::RRect([(4f32, 4f32); 4]),
+    ClipShape::Path(Path::new()),
+  ] {
+    let (child, rect) = probe((100f32, 300f32));
+
+    let mut simulator = Simulator::new(
+      ScrollView {
+        child: Clip {
+          shape,
+          ..Clip::new(child.into_view())
+        }
+        .into_view(),
+        ..Default::default()
+      }
+      .into_view(),
+      (100, 100),
+    );
+    simulator.draw();
+
+    assert_eq!(rect.get().size, (100f32, 300f32));
+  }
+}
@@ -0,0 +1,79 @@
+use skia_test::{
+  layouts::AnimatedShader,
+  nodes::{ShaderNode, Uniform},
+  testing::{get_pixel, Simulator},
+  view::{IntoViewFromNode, IntoViewFromStatefulLayout},
+};
+
+const SKSL: &str = r#"
+uniform float u_time;
+uniform float2 u_resolution;
+uniform float4 color;
+
+half4 main(float2 coord) {
+  return half4(color * fract(u_time + coord.x / u_resolution.x));
+}
+"#;
+
+#[test]
+fn effects_are_loaded_from_assets() {
+  let mut simulator = Simulator::new(None, (64, 64)).with_effects();
+
+  assert!(simulator.get_context().get_effect("scanlines").is_some());
+}
+
+// The shader ramps from 0 at the left edge to almost 1 at the right edge, shifted by the time in seconds
+fn get_ramp(x: i32, time: f32) -> u8 {
+  ((time + (x as f32 + 0.5f32) / 64f32).fract() * 255f32).round() as _
+}
+
+fn assert_near(actual: u8, expected: u8) {
+  assert!(actual.abs_diff(expected) <= 2, "{actual} is not near {expected}");
+}
+
+#[test]
+fn animated_shader_draws_as_time_advances() {
+  let mut simulator = Simulator::new(
+    AnimatedShader {
+      shader: ShaderNode {
+        effect: Some(ShaderNode::compile(SKSL)),
+        uniforms: vec![("color".to_owned(), Uniform::Float4([0f32, 1f32, 0f32, 1f32]))],
+        ..Default::default()
+      },
+    }
+    .into_view(),
+    (64, 64),
+  );
+
+  assert_near(get_pixel(simulator.draw(), 16, 32)[1], get_ramp(16, 0f32));
+
+  // 120 ticks per second
+  simulator.advance(30);
+  assert_near(get_pixel(simulator.draw(), 16, 32)[1], get_ramp(16, 0.25f32));
+}
+
+#[test]
+fn shader_draws_with_uniforms() {
+  let mut simulator = Simulator::new(
+    ShaderNode {
+      effect: Some(ShaderNode::compile(SKSL)),
+      uniforms: vec![("color".to_owned(), Uniform::Float4([1f32, 0f32, 0f32, 1f32]))],
+      ..Default::default()
+    }
+    .into_view(),
+    (64, 64),
+  );
+  let image = simulator.draw();
+
+  for x in [0, 16, 32, 48, 63] {
+    let [red, green, blue, _] = get_pixel(image, x, 32);
+    assert_near(red, get_ramp(x, 0f32));
+    assert_eq!((green, blue), (0, 0));
+  }
+}
+
+#[test]
+#[should_panic(expected = "Failed to compile SkSL")]
+fn invalid_sksl_fails_to_compile() {
+  ShaderNode::compile("half4 main(float2 coord) { return undeclared; }");
+}
@@ -0,0 +1,308 @@
+use sdl2::{event::Event, keyboard::Keycode};
+use skia_safe::Color;
+use skia_test::{
+  common::Sharable,
+  layouts::{stateful_layout::State, Children, MultiChildLayout, StatefulLayout, StatelessLayout},
+  models::{Box2D, Constraints},
+  nodes::BoxNode,
+  testing::{events::key_down, Probe, Simulator},
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout, IntoViewFromStatelessLayout},
+  Context, View,
+};
+use std::{
+  cell::{Cell, RefCell},
+  rc::Rc,
+};
+use tinyrand::Rand;
+
+#[derive(Default)]
+struct Counter {
+  ticks: Rc<Cell<usize>>,
+  key_downs: Rc<Cell<usize>>,
+  is_mounted: Rc<Cell<bool>>,
+  makes: Rc<Cell<usize>>,
+}
+
+impl StatefulLayout for Counter {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(CounterState {
+      ticks: Rc::clone(&self.ticks),
+      key_downs: Rc::clone(&self.key_downs),
+      is_mounted: Rc::clone(&self.is_mounted),
+      makes: Rc::clone(&self.makes),
+    }))
+  }
+}
+
+struct CounterState {
+  ticks: Rc<Cell<usize>>,
+  key_downs: Rc<Cell<usize>>,
+  is_mounted: Rc<Cell<bool>>,
+  makes: Rc<Cell<usize>>,
+}
+
+impl State for CounterState {
+  fn on_mount(&mut self, _context: &mut Context) {
+    self.ticks.set(0);
+    self.is_mounted.set(true);
+  }
+
+  fn on_unmount(&mut self, _context: &mut Context) {
+    self.is_mounted.set(false);
+  }
+
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    if let Event::KeyDown { .. } = event {
+      context.set_state(|| self.key_downs.set(self.key_downs.get() + 1));
+    }
+  }
+
+  fn tick(&mut self, _context: &mut Context, _dt: f32) {
+    self.ticks.set(self.ticks.get() + 1);
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    self.makes.set(self.makes.get() + 1);
+
+    BoxNode {
+      style: if self.key_downs.get() == 0 {
+        Color::RED
+      } else {
+        Color::GREEN
+      }
+      .into(),
+    }
+    .into_view()
+  }
+}
+
+#[derive(Default)]
+struct Pair {
+  ticks: [Rc<Cell<usize>>; 2],
+}
+
+impl MultiChildLayout for Pair {
+  fn make(&self, _constraint: Box2D) -> Vec<Sharable<View>> {
+    self
+      .ticks
+      .iter()
+      .flat_map(|ticks| {
+        Counter {
+          ticks: Rc::clone(ticks),
+          ..Default::default()
+        }
+        .into_view()
+      })
+      .collect()
+  }
+}
+
+#[derive(Default)]
+struct Toggle {
+  is_shown: Rc<Cell<bool>>,
+  ticks: Rc<Cell<usize>>,
+  is_mounted: Rc<Cell<bool>>,
+}
+
+impl StatelessLayout for Toggle {
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    if !self.is_shown.get() {
+      return None;
+    }
+
+    Counter {
+      ticks: Rc::clone(&self.ticks),
+      is_mounted: Rc::clone(&self.is_mounted),
+      ..Default::default()
+    }
+    .into_view()
+  }
+}
+
+struct Stacked {
+  sizes: Vec<(f32, f32)>,
+  rects: Vec<Rc<Cell<Box2D>>>,
+}
+
+impl MultiChildLayout for Stacked {
+  fn measure(&self, constraints: Constraints, children: &mut Children) -> (f32, f32) {
+    let (w, h) = (0..children.len()).fold((0f32, 0f32), |(w, h), index| {
+      let size = children.measure(index, constraints.loosen());
+      (w.max(size.0), h + size.1)
+    });
+
+    constraints.constrain((w, h))
+  }
+
+  fn arrange(&self, rect: Box2D, children: &mut Children) -> Vec<Box2D> {
+    let mut y = rect.position.1;
+
+    (0..children.len())
+      .map(|index| {
+        let size = children.measure(index, Constraints::loose(rect.size));
+        let child_rect = Box2D {
+          position: (rect.position.0, y),
+          size,
+        };
+        y += size.1;
+        child_rect
+      })
+      .collect()
+  }
+
+  fn make(&self, _constraint: Box2D) -> Vec<Sharable<View>> {
+    self
+      .sizes
+      .iter()
+      .zip(&self.rects)
+      .flat_map(|(&size, rect)| {
+        Probe {
+          size,
+          rect: Rc::clone(rect),
+          ..Default::default()
+        }
+        .into_view()
+      })
+      .collect()
+  }
+}
+
+#[test]
+fn advance_runs_exact_ticks() {
+  let counter = Counter::default();
+  let ticks = Rc::clone(&counter.ticks);
+  let mut simulator = Simulator::new(counter.into_view(), (64, 64));
+
+  simulator.advance(10);
+
+  assert_eq!(ticks.get(), 10);
+  assert_eq!(simulator.get_tick_count(), 10);
+}
+
+#[test]
+fn sibling_stateful_layouts_keep_separate_states() {
+  let pair = Pair::default();
+  let ticks = pair.ticks.clone();
+  let mut simulator = Simulator::new(pair.into_view(), (64, 64));
+
+  simulator.advance(3);
+
+  assert_eq!(ticks[0].get(), 3);
+  assert_eq!(ticks[1].get(), 3);
+}
+
+#[test]
+fn unmounted_states_are_dropped() {
+  let toggle = Toggle::default();
+  let is_shown = Rc::clone(&toggle.is_shown);
+  let ticks = Rc::clone(&toggle.ticks);
+  let is_mounted = Rc::clone(&toggle.is_mounted);
+  let mut simulator = Simulator::new(toggle.into_view(), (64, 64));
+
+  is_shown.set(true);
+  simulator.advance(5);
+  assert!(is_mounted.get());
+  assert_eq!(ticks.get(), 5);
+
+  is_shown.set(false);
+  simulator.advance(1);
+  assert!(!is_mounted.get());
+
+  // A new state is made when the layout comes back
+  is_shown.set(true);
+  simulator.advance(2);
+  assert!(is_mounted.get());
+  assert_eq!(ticks.get(), 2);
+}
+
+#[test]
+fn make_is_only_called_when_dirty_or_constraint_changes() {
+  let counter = Counter::default();
+  let makes = Rc::clone(&counter.makes);
+  let mut simulator = Simulator::new(counter.into_view(), (64, 64));
+
+  simulator.advance(10);
+  assert_eq!(makes.get(), 1);
+
+  // The first draw has a different constraint from ticks
+  simulator.draw();
+  simulator.draw();
+  simulator.advance(10);
+  assert_eq!(makes.get(), 2);
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.advance(10);
+  simulator.draw();
+  assert_eq!(makes.get(), 3);
+}
+
+#[test]
+fn children_are_drawn_in_arranged_rects() {
+  let stacked = Stacked {
+    sizes: vec![(16f32, 8f32), (100f32, 24f32)],
+    rects: vec![Rc::default(), Rc::default()],
+  };
+  let rects = stacked.rects.clone();
+  let mut simulator = Simulator::new(stacked.into_view(), (64, 64));
+
+  simulator.draw();
+
+  assert_eq!(
+    rects[0].get(),
+    Box2D {
+      position: (0f32, 0f32),
+      size: (16f32, 8f32),
+    }
+  );
+
+  // Sizes larger than the rect of the parent are constrained to it
+  assert_eq!(
+    rects[1].get(),
+    Box2D {
+      position: (0f32, 8f32),
+      size: (64f32, 24f32),
+    }
+  );
+}
+
+#[test]
+fn events_are_dispatched_before_next_tick() {
+  let counter = Counter::default();
+  let key_downs = Rc::clone(&counter.key_downs);
+  let mut simulator = Simulator::new(counter.into_view(), (64, 64));
+
+  simulator.push_event(key_down(Keycode::W));
+  simulator.push_event(key_down(Keycode::Up));
+  assert_eq!(key_downs.get(), 0);
+
+  simulator.tick();
+  assert_eq!(key_downs.get(), 2);
+}
+
+#[test]
+fn advance_frame_limits_ticks_per_frame() {
+  let counter = Counter::default();
+  let ticks = Rc::clone(&counter.ticks);
+  let mut simulator = Simulator::new(counter.into_view(), (64, 32));
+
+  let frame = simulator.advance_frame(1f32);
+  assert_eq!((frame.width(), frame.height()), (64, 32));
+
+  assert_eq!(ticks.get(), 8);
+  assert_eq!(simulator.get_frame_count(), 1);
+}
+
+#[test]
+fn same_seed_replays_same_random_numbers() {
+  let mut simulators = [0, 1].map(|_| Simulator::new(None, (64, 64)).with_seed(887522));
+  let [first, second] = &mut simulators;
+
+  assert_eq!(first.get_context().get_seed(), 887522);
+
+  for _ in 0..16 {
+    assert_eq!(
+      first.get_context().get_rng().next_u64(),
+      second.get_context().get_rng().next_u64()
+    );
+  }
+}
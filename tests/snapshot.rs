@@ -0,0 +1,195 @@
+use skia_safe::{paint::Cap, Color};
+use skia_test::{
+  common::Sharable,
+  layouts::{Shake, Stack, StackChild},
+  models::{Box2D, Fill, Gradient, Insets, Shadow, Stroke, Style},
+  nodes::{BoxNode, CircleNode, GridNode, LineNode, RRectNode},
+  testing::Snapshot,
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout},
+  View,
+};
+use std::{cell::Cell, rc::Rc};
+
+// Leaves room around the child for its strokes and shadows, which are drawn outside of its rect
+fn inset(child: Option<Sharable<View>>) -> Option<Sharable<View>> {
+  Stack {
+    children: vec![StackChild {
+      insets: Insets::all(32f32),
+      child: child.map(Sharable::into_shared),
+      ..Default::default()
+    }],
+    ..Default::default()
+  }
+  .into_view()
+}
+
+#[test]
+fn box_node_fills_constraint() {
+  Snapshot::default().assert_matches(
+    "box_node",
+    &mut BoxNode {
+      style: Color::RED.into(),
+    }
+    .into_view(),
+  );
+}
+
+#[test]
+fn grid_node_aligns_cells_to_bottom_center() {
+  Snapshot::default().assert_matches(
+    "grid_node",
+    &mut GridNode {
+      dim: (3, 2),
+      gap: (8f32, 8f32),
+      size: (128f32, 128f32),
+      maker: Box::new(|index| {
+        Box::new(BoxNode {
+          style: if index % 2 == 0 { Color::CYAN } else { Color::GREEN }.into(),
+        })
+      }),
+    }
+    .into_view(),
+  );
+}
+
+#[test]
+fn shake_translates_child() {
+  Snapshot::default().assert_matches(
+    "shake",
+    &mut Shake {
+      is_enabled: Rc::new(Cell::new(true)),
+      child: BoxNode {
+        style: Color::RED.into(),
+      }
+      .into_view(),
+    }
+    .into_view(),
+  );
+}
+
+#[test]
+fn rrect_node_fills_and_strokes_rounded_corners() {
+  Snapshot::default().assert_matches(
+    "rrect_node",
+    &mut inset(
+      RRectNode {
+        fill: Some(Color::BLUE.into()),
+        stroke: Some(Stroke {
+          style: Color::YELLOW.into(),
+          width: 8f32,
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+      .with_radius(32f32)
+      .into_view(),
+    ),
+  );
+}
+
+#[test]
+fn circle_node_fits_in_center() {
+  Snapshot {
+    size: (256, 128),
+    ..Default::default()
+  }
+  .assert_matches(
+    "circle_node",
+    &mut CircleNode {
+      fill: Some(Color::MAGENTA.into()),
+      ..Default::default()
+    }
+    .into_view(),
+  );
+}
+
+#[test]
+fn line_node_draws_dashes() {
+  Snapshot::default().assert_matches(
+    "line_node",
+    &mut LineNode {
+      from: (16f32, 128f32),
+      to: (240f32, 128f32),
+      stroke: Stroke {
+        width: 8f32,
+        cap: Cap::Round,
+        dash: vec![16f32, 16f32],
+        ..Default::default()
+      },
+    }
+    .into_view(),
+  );
+}
+
+#[test]
+#[should_panic(expected = "dash must have an even number of intervals")]
+fn stroke_rejects_odd_dash() {
+  Stroke {
+    dash: vec![1f32],
+    ..Default::default()
+  }
+  .make_paint(Box2D::default());
+}
+
+#[test]
+fn box_node_fills_linear_gradient() {
+  Snapshot::default().assert_matches(
+    "box_node_linear_gradient",
+    &mut BoxNode {
+      style: Fill::LinearGradient {
+        from: (0f32, 0f32),
+        to: (1f32, 1f32),
+        gradient: Gradient {
+          colors: vec![Color::RED, Color::BLUE],
+          ..Default::default()
+        },
+      }
+      .into(),
+    }
+    .into_view(),
+  );
+}
+
+#[test]
+fn rrect_node_casts_drop_shadow() {
+  Snapshot {
+    color: Color::WHITE,
+    ..Default::default()
+  }
+  .assert_matches(
+    "rrect_node_drop_shadow",
+    &mut inset(
+      RRectNode {
+        fill: Some(Style {
+          fill: Fill::RadialGradient {
+            center: (0.5f32, 0.5f32),
+            radius: 0.5f32,
+            gradient: Gradient {
+              colors: vec![Color::YELLOW, Color::RED],
+              stops: vec![0.25f32, 1f32],
+            },
+          },
+          opacity: 0.75f32,
+          shadow: Some(Shadow {
+            offset: (8f32, 8f32),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+      .with_radius(16f32)
+      .into_view(),
+    ),
+  );
+}
+
+#[test]
+#[should_panic(expected = "opacity must be between 0 and 1")]
+fn style_rejects_invalid_opacity() {
+  Style {
+    opacity: 2f32,
+    ..Default::default()
+  }
+  .make_paint(Box2D::default());
+}
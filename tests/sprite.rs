@@ -0,0 +1,151 @@
+use sdl2::{event::Event, keyboard::Keycode};
+use skia_safe::{surfaces, IRect, Image};
+use skia_test::{
+  common::{Sharable, SpriteAtlas},
+  layouts::{stateful_layout::State, AnimatedSprite, Flex, FlexChild, StatefulLayout},
+  models::Box2D,
+  nodes::SpriteNode,
+  testing::{events::key_down, Probe, Simulator},
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout},
+  Context, View,
+};
+use std::{
+  cell::{Cell, RefCell},
+  collections::HashMap,
+  rc::Rc,
+};
+
+const ATLAS_JSON: &str = r#"{
+  "image": "snake",
+  "frames": { "small": [0, 0, 8, 8], "large": [8, 0, 16, 8] },
+  "animations": {
+    "grow": { "frames": ["small", "large"], "frame_duration": 0.25 },
+    "pulse": { "frames": ["small", "large"], "frame_duration": 0.25, "looping": true }
+  }
+}"#;
+
+fn make_atlas() -> Rc<SpriteAtlas> {
+  let image: Image = surfaces::raster_n32_premul((24, 8)).unwrap().image_snapshot();
+  Rc::new(SpriteAtlas::from_json(
+    ATLAS_JSON,
+    &HashMap::from([("snake".to_owned(), image)]),
+  ))
+}
+
+// The x position of the probe after the sprite tells the width of the frame being drawn
+fn make_row(animation: &str, rect: &Rc<Cell<Box2D>>) -> Flex {
+  Flex::row(vec![
+    AnimatedSprite {
+      sprite: SpriteNode {
+        atlas: Some(make_atlas()),
+        ..Default::default()
+      },
+      animation: animation.to_owned(),
+    }
+    .into_view()
+    .into(),
+    FlexChild::new(
+      Probe {
+        size: (1f32, 1f32),
+        rect: Rc::clone(rect),
+        ..Default::default()
+      }
+      .into_view(),
+    ),
+  ])
+}
+
+fn make_simulator(animation: &str) -> (Simulator, Rc<Cell<Box2D>>) {
+  let rect = Rc::new(Cell::new(Box2D::default()));
+  (Simulator::new(make_row(animation, &rect).into_view(), (64, 64)), rect)
+}
+
+// Plays the pulse animation until a key is pressed, then the grow animation
+struct Switch {
+  rect: Rc<Cell<Box2D>>,
+}
+
+impl StatefulLayout for Switch {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(SwitchState {
+      animation: "pulse",
+      rect: Rc::clone(&self.rect),
+    }))
+  }
+}
+
+struct SwitchState {
+  animation: &'static str,
+  rect: Rc<Cell<Box2D>>,
+}
+
+impl State for SwitchState {
+  fn on_event(&mut self, context: &mut Context, event: &Event) {
+    if let Event::KeyDown { .. } = event {
+      context.set_state(|| self.animation = "grow");
+    }
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    make_row(self.animation, &self.rect).into_view()
+  }
+}
+
+#[test]
+fn atlas_maps_names_to_frames_and_animations() {
+  let atlas = make_atlas();
+
+  assert_eq!(atlas.get_frame("large"), Some(IRect::from_xywh(8, 0, 16, 8)));
+  assert_eq!(atlas.get_frame("missing"), None);
+  assert_eq!(atlas.get_animation("grow").unwrap().frames, vec!["small", "large"]);
+  assert!(!atlas.get_animation("grow").unwrap().looping);
+  assert!(atlas.get_animation("pulse").unwrap().looping);
+}
+
+#[test]
+fn animation_stops_at_last_frame() {
+  let (mut simulator, rect) = make_simulator("grow");
+
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 8f32);
+
+  simulator.advance(32);
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 16f32);
+
+  simulator.advance(60);
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 16f32);
+}
+
+#[test]
+fn looping_animation_restarts_from_first_frame() {
+  let (mut simulator, rect) = make_simulator("pulse");
+
+  simulator.advance(32);
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 16f32);
+
+  simulator.advance(32);
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 8f32);
+}
+
+#[test]
+fn another_animation_restarts_from_first_frame() {
+  let rect = Rc::new(Cell::new(Box2D::default()));
+  let mut simulator = Simulator::new(Switch { rect: Rc::clone(&rect) }.into_view(), (64, 64));
+
+  simulator.advance(32);
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 16f32);
+
+  simulator.push_event(key_down(Keycode::Space));
+  simulator.tick();
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 8f32);
+
+  simulator.advance(32);
+  simulator.draw();
+  assert_eq!(rect.get().position.0, 16f32);
+}
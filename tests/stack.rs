@@ -0,0 +1,179 @@
+use skia_safe::Canvas;
+use skia_test::{
+  common::Sharable,
+  layouts::{stateful_layout::State, Stack, StackChild, StatefulLayout, StatelessLayout},
+  models::{Alignment, Box2D, Insets},
+  nodes::BoxNode,
+  testing::{probe, take, Log, Probe, Simulator},
+  view::{IntoViewFromMultiChildLayout, IntoViewFromNode, IntoViewFromStatefulLayout, IntoViewFromStatelessLayout},
+  Context, View,
+};
+use std::{
+  cell::{Cell, RefCell},
+  rc::Rc,
+};
+
+#[test]
+fn children_share_the_rect_of_the_stack() {
+  let (background, background_rect) = probe((f32::MAX, f32::MAX));
+  let (panel, panel_rect) = probe((20f32, 10f32));
+  let (score, score_rect) = probe((30f32, 8f32));
+
+  let stack = Stack {
+    children: vec![
+      background.into_view().into(),
+      StackChild {
+        alignment: Alignment::CENTER,
+        ..StackChild::new(panel.into_view())
+      },
+      StackChild {
+        alignment: Alignment::TOP_RIGHT,
+        insets: Insets::all(4f32),
+        ..StackChild::new(score.into_view())
+      },
+    ],
+    ..Default::default()
+  };
+
+  Simulator::new(stack.into_view(), (100, 50)).draw();
+
+  assert_eq!(
+    background_rect.get(),
+    Box2D {
+      position: (0f32, 0f32),
+      size: (100f32, 50f32),
+    }
+  );
+
+  assert_eq!(
+    panel_rect.get(),
+    Box2D {
+      position: (40f32, 20f32),
+      size: (20f32, 10f32),
+    }
+  );
+
+  assert_eq!(
+    score_rect.get(),
+    Box2D {
+      position: (66f32, 4f32),
+      size: (30f32, 8f32),
+    }
+  );
+}
+
+#[test]
+fn children_are_drawn_in_z_order() {
+  let draws = Log::default();
+  let [top, bottom, middle] = ["top", "bottom", "middle"].map(|name| Probe {
+    name,
+    size: (10f32, 10f32),
+    draws: Rc::clone(&draws),
+    ..Default::default()
+  });
+
+  let stack = Stack {
+    children: vec![
+      StackChild {
+        z_index: 2,
+        ..StackChild::new(top.into_view())
+      },
+      StackChild {
+        z_index: -1,
+        ..StackChild::new(bottom.into_view())
+      },
+      middle.into_view().into(),
+    ],
+    ..Default::default()
+  };
+
+  Simulator::new(stack.into_view(), (64, 64)).draw();
+
+  assert_eq!(take(&draws), ["bottom", "middle", "top"]);
+}
+
+type Labels = Rc<RefCell<Vec<&'static str>>>;
+
+// Records the label it was first made with, which is kept in its state, each time it is drawn
+struct Labeled {
+  label: &'static str,
+  drawn_labels: Labels,
+}
+
+impl StatefulLayout for Labeled {
+  fn make_state(&mut self, _context: &mut Context) -> Rc<RefCell<dyn State>> {
+    Rc::new(RefCell::new(LabeledState {
+      label: self.label,
+      drawn_labels: Rc::clone(&self.drawn_labels),
+    }))
+  }
+}
+
+struct LabeledState {
+  label: &'static str,
+  drawn_labels: Labels,
+}
+
+impl State for LabeledState {
+  fn pre_draw(&self, _canvas: &Canvas, _constraint: Box2D) {
+    self.drawn_labels.borrow_mut().push(self.label);
+  }
+
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    BoxNode::default().into_view()
+  }
+}
+
+// Puts the second child on top until it is swapped
+struct Swappable {
+  is_swapped: Rc<Cell<bool>>,
+  drawn_labels: Labels,
+}
+
+impl StatelessLayout for Swappable {
+  fn make(&self, _constraint: Box2D) -> Option<Sharable<View>> {
+    let is_swapped = self.is_swapped.get();
+
+    Stack {
+      children: ["a", "b"]
+        .into_iter()
+        .enumerate()
+        .map(|(index, label)| StackChild {
+          z_index: if is_swapped { -(index as i32) } else { index as i32 },
+          ..StackChild::new(
+            Labeled {
+              label,
+              drawn_labels: Rc::clone(&self.drawn_labels),
+            }
+            .into_view(),
+          )
+        })
+        .collect(),
+      ..Default::default()
+    }
+    .into_view()
+  }
+}
+
+#[test]
+fn children_keep_their_state_as_the_z_order_changes() {
+  let is_swapped = Rc::new(Cell::new(false));
+  let drawn_labels = Labels::default();
+
+  let mut simulator = Simulator::new(
+    Swappable {
+      is_swapped: Rc::clone(&is_swapped),
+      drawn_labels: Rc::clone(&drawn_labels),
+    }
+    .into_view(),
+    (64, 64),
+  );
+  simulator.draw();
+
+  assert_eq!(drawn_labels.take(), ["a", "b"]);
+
+  is_swapped.set(true);
+  simulator.draw();
+
+  assert_eq!(drawn_labels.take(), ["b", "a"]);
+}
@@ -0,0 +1,65 @@
+use skia_test::{
+  models::Constraints,
+  nodes::{Node, TextNode},
+  testing::Simulator,
+};
+
+const TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn fonts_are_loaded_from_assets() {
+  let mut simulator = Simulator::new(None, (64, 64)).with_fonts();
+
+  assert!(simulator.get_context().get_typeface("DejaVuSans").is_some());
+  assert!(simulator.get_context().get_typeface("missing").is_none());
+}
+
+#[test]
+fn font_collection_is_made_with_the_fonts() {
+  let mut simulator = Simulator::new(None, (64, 64));
+  assert!(simulator.get_context().get_font_collection().is_none());
+
+  let mut simulator = simulator.with_fonts();
+  assert!(simulator.get_context().get_font_collection().is_some());
+}
+
+#[test]
+fn text_wraps_at_max_width() {
+  let mut simulator = Simulator::new(None, (64, 64)).with_fonts();
+  let context = simulator.get_context();
+
+  let text = TextNode {
+    text: TEXT.to_owned(),
+    typeface: context.get_typeface("DejaVuSans"),
+    font_collection: context.get_font_collection(),
+    ..Default::default()
+  };
+
+  let wide = text.measure(Constraints::loose((1000f32, 1000f32)));
+  let narrow = text.measure(Constraints::loose((100f32, 1000f32)));
+
+  assert!(wide.0 > 100f32);
+  assert!(narrow.0 <= 100f32);
+  assert!(narrow.1 > wide.1);
+
+  // The cached paragraph is broken into lines again for the new width
+  assert_eq!(text.measure(Constraints::loose((1000f32, 1000f32))), wide);
+}
+
+#[test]
+fn max_lines_limits_height() {
+  let text = TextNode {
+    text: TEXT.to_owned(),
+    max_lines: Some(1),
+    ellipsis: true,
+    ..Default::default()
+  };
+
+  let single_line = TextNode {
+    text: "The".to_owned(),
+    ..Default::default()
+  };
+
+  let constraints = Constraints::loose((100f32, 1000f32));
+  assert_eq!(text.measure(constraints).1, single_line.measure(constraints).1);
+}
@@ -0,0 +1,122 @@
+use skia_safe::Color;
+use skia_test::{
+  layouts::Transform,
+  models::{Alignment, Box2D},
+  nodes::BoxNode,
+  testing::{events::mouse_button_down, get_pixel, probe, Probe, Simulator},
+  view::{IntoViewFromNode, IntoViewFromStatelessLayout},
+};
+use std::rc::Rc;
+
+fn click(simulator: &mut Simulator, x: i32, y: i32) {
+  // Resolve the rect of the transform which the pivot depends on
+  simulator.draw();
+
+  simulator.push_event(mouse_button_down(x, y));
+  simulator.dispatch_events();
+}
+
+#[test]
+fn pointer_positions_are_mapped_back_through_the_transform() {
+  let probe = Probe::default();
+  let clicked_at = Rc::clone(&probe.clicked_at);
+
+  let mut simulator = Simulator::new(
+    Transform {
+      origin: Alignment::TOP_LEFT,
+      translation: (10f32, 0f32),
+      scale: (2f32, 2f32),
+      ..Transform::new(probe.into_view())
+    }
+    .into_view(),
+    (100, 100),
+  );
+  click(&mut simulator, 30, 20);
+
+  assert_eq!(clicked_at.get(), Some((10, 10)));
+}
+
+#[test]
+fn rotation_is_around_the_origin() {
+  let probe = Probe::default();
+  let clicked_at = Rc::clone(&probe.clicked_at);
+
+  let mut simulator = Simulator::new(
+    Transform {
+      rotation: 180f32,
+      ..Transform::new(probe.into_view())
+    }
+    .into_view(),
+    (100, 100),
+  );
+  click(&mut simulator, 20, 30);
+
+  assert_eq!(clicked_at.get(), Some((80, 70)));
+}
+
+#[test]
+fn layout_is_not_affected_by_the_transform() {
+  let (probe, rect) = probe((f32::MAX, f32::MAX));
+
+  let mut simulator = Simulator::new(
+    Transform {
+      translation: (10f32, 20f32),
+      rotation: 45f32,
+      scale: (0.5f32, 3f32),
+      skew: (0.25f32, 0f32),
+      opacity: 0.5f32,
+      ..Transform::new(probe.into_view())
+    }
+    .into_view(),
+    (100, 80),
+  );
+  simulator.draw();
+
+  assert_eq!(
+    rect.get(),
+    Box2D {
+      position: (0f32, 0f32),
+      size: (100f32, 80f32),
+    }
+  );
+}
+
+#[test]
+fn transparent_and_empty_transforms_are_drawn() {
+  let make_box = |opacity| {
+    Transform {
+      opacity,
+      ..Transform::new(
+        BoxNode {
+          style: Color::WHITE.into(),
+        }
+        .into_view(),
+      )
+    }
+    .into_view()
+  };
+
+  let mut simulator = Simulator::new(make_box(1f32), (10, 10));
+  assert_eq!(get_pixel(simulator.draw(), 5, 5), [255, 255, 255, 255]);
+
+  // Only the black background is left
+  let mut simulator = Simulator::new(make_box(0f32), (10, 10));
+  assert_eq!(get_pixel(simulator.draw(), 5, 5), [0, 0, 0, 255]);
+
+  let mut simulator = Simulator::new(Transform::default().into_view(), (10, 10));
+  assert_eq!(get_pixel(simulator.draw(), 5, 5), [0, 0, 0, 255]);
+}
+
+#[test]
+#[should_panic(expected = "opacity must be between 0 and 1")]
+fn opacity_greater_than_one_is_rejected() {
+  let mut simulator = Simulator::new(
+    Transform {
+      opacity: 1.5f32,
+      ..Transform::new(BoxNode::default().into_view())
+    }
+    .into_view(),
+    (10, 10),
+  );
+  simulator.draw();
+}